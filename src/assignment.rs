@@ -0,0 +1,119 @@
+use num_traits::Signed;
+
+use crate::{MatrixEntry, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + PartialOrd + Signed> SquareMatrix<N, T> {
+    /// The assignment of rows to columns of the cost matrix `self` with the least total cost, found with the
+    /// Hungarian (Kuhn–Munkres) algorithm in `O(N³)` time.
+    ///
+    /// The `i`th entry of the returned permutation is the column assigned to row `i`, and the second element is the total cost.
+    ///
+    /// # Examples
+    ///
+    /// Match three tracks to three detections by distance.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let cost = SquareMatrix::<3,i32>::new([[4, 1, 3], [2, 0, 5], [3, 2, 2]]);
+    /// assert_eq!(cost.solve_assignment(), ([1, 0, 2], 5));
+    /// ```
+    ///
+    /// Costs may be negative, so maximizing a profit is minimizing its negation.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let profit = SquareMatrix::<2,f64>::new([[3.0, 1.0], [4.0, 3.0]]);
+    /// let (assignment, cost) = (profit * -1.0).solve_assignment();
+    /// assert_eq!((assignment, -cost), ([0, 1], 6.0));
+    /// ```
+    pub fn solve_assignment(&self) -> ([usize; N], T) {
+        // Potentials and matching are indexed from one, with index zero a virtual column used to grow each augmenting path.
+        let mut row_potential = vec![T::zero(); N + 1];
+        let mut col_potential = vec![T::zero(); N + 1];
+        let mut matched_row = vec![0; N + 1];
+        let mut previous_col = vec![0; N + 1];
+        for i in 1..=N {
+            matched_row[0] = i;
+            let mut j0 = 0;
+            let mut min_slack: Vec<Option<T>> = vec![None; N + 1];
+            let mut used = vec![false; N + 1];
+            loop {
+                used[j0] = true;
+                let i0 = matched_row[j0];
+                let mut delta = None;
+                let mut j1 = 0;
+                for j in 1..=N {
+                    if used[j] {
+                        continue;
+                    }
+                    let slack = self.data[i0 - 1][j - 1] - row_potential[i0] - col_potential[j];
+                    if min_slack[j].map_or(true, |min| slack < min) {
+                        min_slack[j] = Some(slack);
+                        previous_col[j] = j0;
+                    }
+                    if delta.map_or(true, |delta| min_slack[j].is_some_and(|min| min < delta)) {
+                        delta = min_slack[j];
+                        j1 = j;
+                    }
+                }
+                let delta = delta.unwrap_or_else(T::zero);
+                for j in 0..=N {
+                    if used[j] {
+                        row_potential[matched_row[j]] = row_potential[matched_row[j]] + delta;
+                        col_potential[j] = col_potential[j] - delta;
+                    } else if let Some(slack) = min_slack[j].as_mut() {
+                        *slack = *slack - delta;
+                    }
+                }
+                j0 = j1;
+                if matched_row[j0] == 0 {
+                    break;
+                }
+            }
+            while j0 != 0 {
+                let j1 = previous_col[j0];
+                matched_row[j0] = matched_row[j1];
+                j0 = j1;
+            }
+        }
+        let mut assignment = [0; N];
+        for j in 1..=N {
+            assignment[matched_row[j] - 1] = j - 1;
+        }
+        let cost = assignment
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |cost, (i, &j)| cost + self.data[i][j]);
+        (assignment, cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check the Hungarian algorithm agrees with trying every assignment of a 4-by-4 cost [`Matrix`]
+    #[test]
+    fn check_assignment_against_brute_force() -> Result<(), Box<dyn Error>> {
+        let cost =
+            SquareMatrix::<4, i64>::new([[9, 2, 7, 8], [6, 4, 3, 7], [5, 8, 1, 8], [7, 6, 9, 4]]);
+        let mut best = i64::MAX;
+        for a in 0..4 {
+            for b in (0..4).filter(|&b| b != a) {
+                for c in (0..4).filter(|&c| c != a && c != b) {
+                    let d = 6 - a - b - c;
+                    let rows = cost.as_slice();
+                    best = best.min(rows[0][a] + rows[1][b] + rows[2][c] + rows[3][d]);
+                }
+            }
+        }
+        let (assignment, total) = cost.solve_assignment();
+        let mut columns = assignment;
+        columns.sort();
+        assert_eq!(columns, [0, 1, 2, 3]);
+        assert_eq!(total, best);
+        Ok(())
+    }
+}
@@ -1,11 +1,11 @@
 use std::ops::{Div, Sub};
 
-use num_traits::{One, Zero};
+use num_traits::{Float, One, Zero};
 
-use crate::{Matrix, MatrixEntry, RowOps};
+use crate::{CommutativeRing, Matrix, MatrixEntry, MatrixError, PivotStrategy, RowOps};
 
 /// `M`-by-`(N+P)` rectangular matrix `[A|B]` formed by augmenting a `M`-by-`N` matrix `A` with a `M`-by-`P` matrix `B`.
-#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+#[derive(Eq, PartialEq, Clone, Copy)]
 pub struct AugmentedMatrix<const M: usize, const N: usize, const P: usize, T: MatrixEntry> {
     left: Matrix<M, N, T>,
     right: Matrix<M, P, T>,
@@ -24,6 +24,244 @@ impl<const M: usize, const N: usize, const P: usize, T: MatrixEntry> AugmentedMa
     pub fn get_right(&self) -> &Matrix<M, P, T> {
         &self.right
     }
+    /// Split the augmented matrix `[A|B]` back into its parts `A` and `B`, taking ownership of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{AugmentedMatrix, Matrix};
+    /// let a = Matrix::<2,2,i32>::new([[1, 2], [3, 4]]);
+    /// let b = Matrix::<2,1,i32>::new([[5], [6]]);
+    ///
+    /// let augmented = AugmentedMatrix::from((a, b));
+    /// assert_eq!(augmented.into_parts(), (a, b));
+    ///
+    /// let (left, right): (Matrix<2,2,i32>, Matrix<2,1,i32>) = a.augment(&b).into();
+    /// assert_eq!((left, right), (a, b));
+    /// ```
+    pub fn into_parts(self) -> (Matrix<M, N, T>, Matrix<M, P, T>) {
+        (self.left, self.right)
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize, T: MatrixEntry>
+    From<(Matrix<M, N, T>, Matrix<M, P, T>)> for AugmentedMatrix<M, N, P, T>
+{
+    /// Augment the first matrix of the pair with the second, as in [`AugmentedMatrix::new`].
+    fn from((a, b): (Matrix<M, N, T>, Matrix<M, P, T>)) -> Self {
+        AugmentedMatrix::new(a, b)
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize, T: MatrixEntry>
+    From<AugmentedMatrix<M, N, P, T>> for (Matrix<M, N, T>, Matrix<M, P, T>)
+{
+    /// Split the augmented matrix into its parts, as in [`AugmentedMatrix::into_parts`].
+    fn from(augmented: AugmentedMatrix<M, N, P, T>) -> Self {
+        augmented.into_parts()
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize, T: MatrixEntry + Float>
+    AugmentedMatrix<M, N, P, T>
+{
+    /// The Frobenius norm of the left hand part of the augmented matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[3.0, 0.0], [0.0, 4.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[1.0], [2.0]]);
+    /// assert_eq!(a.augment(&b).left_norm(), 5.0);
+    /// ```
+    pub fn left_norm(&self) -> T {
+        self.left.frobenius_norm()
+    }
+    /// The Frobenius norm of the right hand part of the augmented matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 0.0], [0.0, 1.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[3.0], [4.0]]);
+    /// assert_eq!(a.augment(&b).right_norm(), 5.0);
+    /// ```
+    pub fn right_norm(&self) -> T {
+        self.right.frobenius_norm()
+    }
+}
+
+impl<
+        const M: usize,
+        const N: usize,
+        const P: usize,
+        T: MatrixEntry + Div<Output = T> + Sub<Output = T> + Zero + One + CommutativeRing,
+    > AugmentedMatrix<M, N, P, T>
+{
+    /// The rank of the left hand part of the augmented matrix, the number of nonzero rows in its row echelon form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,f64>::new([[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]]);
+    /// let b = Matrix::<3,1,f64>::new([[1.0], [2.0], [3.0]]);
+    /// assert_eq!(a.augment(&b).left_rank(), 1);
+    /// ```
+    pub fn left_rank(&self) -> usize {
+        self.left.rank()
+    }
+
+    /// The solution `X` of the system `AX = B` represented by the augmented matrix `[A|B]`, found by Gauss–Jordan
+    /// elimination of a working copy and reading `X` off the right hand part.
+    ///
+    /// Pivots are chosen by the [`CommutativeRing::pivot_strategy`] of `T`, so floating point systems are reduced with
+    /// partial pivoting, and entries left behind by rounding errors count as zero when checking for solutions.
+    ///
+    /// If some column of `B` is not a combination of the columns of `A`, get [`MatrixError::Inconsistent`],
+    /// and if `A` has dependent columns, so that solutions are not unique, get [`MatrixError::Underdetermined`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,f64>::new([[1.0, 1.0], [1.0, -1.0], [2.0, 0.0]]);
+    /// let b = Matrix::<3,2,f64>::new([[3.0, 0.0], [1.0, 2.0], [4.0, 2.0]]);
+    /// assert_eq!(a.augment(&b).solve(), Ok(Matrix::new([[2.0, 1.0], [1.0, -1.0]])));
+    /// ```
+    ///
+    /// ```
+    /// # use malg::{Matrix, MatrixError};
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// let consistent = Matrix::<2,1,f64>::new([[1.0], [2.0]]);
+    /// let inconsistent = Matrix::<2,1,f64>::new([[1.0], [3.0]]);
+    /// assert_eq!(a.augment(&consistent).solve(), Err(MatrixError::Underdetermined));
+    /// assert_eq!(a.augment(&inconsistent).solve(), Err(MatrixError::Inconsistent));
+    /// ```
+    pub fn solve(&self) -> Result<Matrix<N, P, T>, MatrixError> {
+        let left_strategy = T::pivot_strategy(self.left);
+        let right_strategy = T::pivot_strategy(self.right);
+        let mut reduced = *self;
+        let mut left_rank = 0;
+        for j in 0..N {
+            if reduced.eliminate_column(left_rank, j, &left_strategy) {
+                left_rank += 1;
+            }
+        }
+        if reduced.right.data[left_rank..]
+            .iter()
+            .flatten()
+            .any(|entry| !right_strategy.is_negligible(entry))
+        {
+            return Err(MatrixError::Inconsistent);
+        }
+        if left_rank < N {
+            return Err(MatrixError::Underdetermined);
+        }
+        reduced.eliminate_above_diagonal();
+        Ok(Matrix::new(std::array::from_fn(|i| reduced.right.data[i])))
+    }
+
+    /// Clear the entries above the diagonal of the left block, assuming it is in row echelon form with ones on the
+    /// diagonal, by subtracting multiples of each row from the rows above it.
+    fn eliminate_above_diagonal(&mut self) {
+        for j in (1..M.min(N)).rev() {
+            for i in 0..j {
+                let entry = self.left.data[i][j];
+                if !entry.is_zero() {
+                    self.add_rows(i, j, T::zero() - entry);
+                }
+            }
+        }
+    }
+}
+
+impl<const N: usize, const P: usize, T: MatrixEntry + Zero> AugmentedMatrix<N, N, P, T> {
+    /// The trace of the square left hand part of the augmented matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,u8>::new([[1, 2], [3, 4]]);
+    /// let b = Matrix::<2,1,u8>::new([[5], [6]]);
+    /// assert_eq!(a.augment(&b).left_trace(), 5);
+    /// ```
+    pub fn left_trace(&self) -> T {
+        self.left.trace()
+    }
+}
+
+impl<const N: usize, const P: usize, T: MatrixEntry + Float> AugmentedMatrix<N, N, P, T> {
+    /// The determinant of the square left hand part of the augmented matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[5.0], [6.0]]);
+    /// assert_eq!(a.augment(&b).left_determinant(), -2.0);
+    /// ```
+    pub fn left_determinant(&self) -> T {
+        self.left.determinant()
+    }
+}
+
+impl<
+        const N: usize,
+        const P: usize,
+        T: MatrixEntry + Div<Output = T> + Sub<Output = T> + Zero + One + CommutativeRing,
+    > AugmentedMatrix<N, N, P, T>
+{
+    /// Reduce the square left block to the identity by Gauss–Jordan elimination,
+    /// applying the same row operations to the right block.
+    ///
+    /// Pivots are chosen by the [`CommutativeRing::pivot_strategy`] of `T`, so floating point blocks are reduced with
+    /// partial pivoting, and a block that is singular up to rounding errors is recognized as singular.
+    ///
+    /// Returns `true` on success. If the left block is singular, `false` is returned
+    /// and `self` is left partially reduced.
+    ///
+    /// # Examples
+    ///
+    /// Reducing `[A|I]` leaves the inverse of `A` in the right block,
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 1.0]]);
+    /// let mut a_i = a.augment_identity();
+    ///
+    /// assert!(a_i.reduce_left_to_identity());
+    /// assert_eq!(*a_i.get_left(), SquareMatrix::<2,f64>::new([[1.0, 0.0], [0.0, 1.0]]));
+    /// assert_eq!(*a_i.get_right(), SquareMatrix::<2,f64>::new([[1.0, -1.0], [-1.0, 2.0]]));
+    /// ```
+    ///
+    /// But a singular left block cannot be reduced.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// let mut a_i = a.augment_identity();
+    ///
+    /// assert!(!a_i.reduce_left_to_identity());
+    ///
+    /// // Singular, although rounding errors leave a tiny nonzero last pivot.
+    /// let a = SquareMatrix::<3,f64>::new([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+    /// assert!(!a.augment_identity().reduce_left_to_identity());
+    /// ```
+    pub fn reduce_left_to_identity(&mut self) -> bool {
+        let strategy = T::pivot_strategy(self.left);
+        for j in 0..N {
+            if !self.eliminate_column(j, j, &strategy) {
+                return false;
+            }
+        }
+        self.eliminate_above_diagonal();
+        true
+    }
 }
 
 impl<
@@ -153,6 +391,10 @@ impl<
     fn get_row(&self, i: usize) -> Vec<T> {
         self.left.get_row(i)
     }
+    /// The entry in row `i` and column `j` of the left part of the matrix, read without copying the row.
+    fn get_row_entry(&self, i: usize, j: usize) -> T {
+        self.left.get_row_entry(i, j)
+    }
     /// The number of rows in the left part of the matrix, `M`.
     ///
     /// ## Examples
@@ -186,3 +428,38 @@ impl<
         N
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check solving a system whose leading entry is tiny pivots on the larger entry below it
+    #[test]
+    fn check_solve_tiny_pivot() -> Result<(), Box<dyn Error>> {
+        let a = Matrix::<2, 2, f64>::new([[1e-20, 1.0], [1.0, 1.0]]);
+        let b = Matrix::<2, 1, f64>::new([[1.0], [2.0]]);
+        let x = a.augment(&b).solve()?;
+        assert!(x.distance_frobenius(&Matrix::new([[1.0], [1.0]])) < 1e-15);
+        Ok(())
+    }
+
+    /// Check a consistent system with a singular float [`Matrix`] is underdetermined despite rounding errors,
+    /// and an inconsistent one is still recognized
+    #[test]
+    fn check_solve_numerically_singular() -> Result<(), Box<dyn Error>> {
+        let a = Matrix::<3, 3, f64>::new([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+        let consistent = Matrix::<3, 1, f64>::new([[0.6], [1.5], [2.4]]);
+        let inconsistent = Matrix::<3, 1, f64>::new([[0.6], [1.5], [2.5]]);
+        assert_eq!(
+            a.augment(&consistent).solve(),
+            Err(MatrixError::Underdetermined)
+        );
+        assert_eq!(
+            a.augment(&inconsistent).solve(),
+            Err(MatrixError::Inconsistent)
+        );
+        Ok(())
+    }
+}
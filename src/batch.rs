@@ -0,0 +1,223 @@
+use std::ops::{Add, Mul};
+
+use num_traits::Float;
+
+use crate::{Matrix, MatrixEntry};
+
+/// `B` matrices of size `M`-by-`N` stored in struct-of-arrays layout, with the `B` values of each entry contiguous.
+///
+/// Arithmetic works entry by entry across the whole batch, so the innermost loops run over the batch dimension with the
+/// same operation in every lane, which vectorizes well even for tiny matrices such as 3-by-3 or 4-by-4 ones whose own
+/// loops are too short to.
+///
+/// # Examples
+///
+/// ```
+/// # use num_traits::One;
+/// # use malg::{MatrixBatch, SquareMatrix};
+/// let rotation = SquareMatrix::<2,f64>::new([[0.0, -1.0], [1.0, 0.0]]);
+/// let scaling = SquareMatrix::<2,f64>::new([[2.0, 0.0], [0.0, 3.0]]);
+///
+/// let a = MatrixBatch::from_matrices(&[rotation, scaling]);
+/// let b = MatrixBatch::from_matrices(&[rotation, SquareMatrix::one()]);
+/// let product = a * b;
+///
+/// assert_eq!(product.get_matrix(0), Some(rotation * rotation));
+/// assert_eq!(product.get_matrix(1), Some(scaling));
+/// assert_eq!(product.get_matrix(2), None);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct MatrixBatch<const B: usize, const M: usize, const N: usize, T: MatrixEntry> {
+    data: [[[T; B]; N]; M],
+}
+
+impl<const B: usize, const M: usize, const N: usize, T: MatrixEntry> MatrixBatch<B, M, N, T> {
+    /// Create a batch from its entries in struct-of-arrays layout, where `data[i][j][k]` is the `(i, j)` entry
+    /// of the `k`th matrix.
+    pub fn new(data: [[[T; B]; N]; M]) -> Self {
+        MatrixBatch { data }
+    }
+    /// Gather the `matrices` into a batch, transposing them from array-of-structs layout.
+    pub fn from_matrices(matrices: &[Matrix<M, N, T>; B]) -> Self {
+        MatrixBatch {
+            data: std::array::from_fn(|i| {
+                std::array::from_fn(|j| std::array::from_fn(|k| matrices[k].data[i][j]))
+            }),
+        }
+    }
+    /// Scatter the batch back into separate matrices.
+    pub fn to_matrices(&self) -> [Matrix<M, N, T>; B] {
+        std::array::from_fn(|k| {
+            Matrix::new(std::array::from_fn(|i| {
+                std::array::from_fn(|j| self.data[i][j][k])
+            }))
+        })
+    }
+    /// The `k`th matrix of the batch. If `k` lies outside of the batch, get [`None`] instead.
+    pub fn get_matrix(&self, k: usize) -> Option<Matrix<M, N, T>> {
+        if k >= B {
+            return None;
+        }
+        Some(Matrix::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.data[i][j][k])
+        })))
+    }
+    /// The entries of the batch in struct-of-arrays layout, as in [`MatrixBatch::new`].
+    pub fn as_lanes(&self) -> &[[[T; B]; N]; M] {
+        &self.data
+    }
+}
+
+impl<const B: usize, const M: usize, const N: usize, T: MatrixEntry + Add<Output = T>> Add
+    for MatrixBatch<B, M, N, T>
+{
+    type Output = Self;
+    /// Add corresponding matrices of two batches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, MatrixBatch};
+    /// let a = MatrixBatch::<2,1,2,i32>::new([[[1, 2], [3, 4]]]);
+    /// let b = MatrixBatch::<2,1,2,i32>::new([[[10, 20], [30, 40]]]);
+    /// assert_eq!((a + b).get_matrix(1), Some(Matrix::new([[22, 44]])));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        let mut sum = self.data;
+        for (sum_row, rhs_row) in sum.iter_mut().zip(rhs.data) {
+            for (sum_lanes, rhs_lanes) in sum_row.iter_mut().zip(rhs_row) {
+                for (a, b) in sum_lanes.iter_mut().zip(rhs_lanes) {
+                    *a = *a + b;
+                }
+            }
+        }
+        MatrixBatch { data: sum }
+    }
+}
+
+impl<
+        const B: usize,
+        const M: usize,
+        const N: usize,
+        const P: usize,
+        T: MatrixEntry + Mul<Output = T> + Add<Output = T>,
+    > Mul<MatrixBatch<B, N, P, T>> for MatrixBatch<B, M, N, T>
+{
+    type Output = MatrixBatch<B, M, P, T>;
+    /// Multiply corresponding matrices of two batches.
+    fn mul(self, rhs: MatrixBatch<B, N, P, T>) -> Self::Output {
+        let mut product = [[[T::default(); B]; P]; M];
+        for (product_row, self_row) in product.iter_mut().zip(self.data.iter()) {
+            for (self_lanes, rhs_row) in self_row.iter().zip(rhs.data.iter()) {
+                for (product_lanes, rhs_lanes) in product_row.iter_mut().zip(rhs_row) {
+                    for ((c, a), b) in product_lanes.iter_mut().zip(self_lanes).zip(rhs_lanes) {
+                        *c = *c + *a * *b;
+                    }
+                }
+            }
+        }
+        MatrixBatch { data: product }
+    }
+}
+
+impl<const B: usize, const N: usize, T: MatrixEntry + Float> MatrixBatch<B, N, N, T> {
+    /// Solve `AₖXₖ = Bₖ` for every matrix `Aₖ` of the batch and the corresponding right-hand sides `Bₖ` of `rhs`,
+    /// by Gaussian elimination with partial pivoting run in every lane at once.
+    /// If any matrix of the batch is singular, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, MatrixBatch, SquareMatrix};
+    /// let a = [
+    ///     SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 3.0]]),
+    ///     SquareMatrix::<2,f64>::new([[0.0, 1.0], [4.0, 0.0]]),
+    /// ];
+    /// let x = [Matrix::<2,1,f64>::new([[1.0], [2.0]]), Matrix::new([[3.0], [-1.0]])];
+    /// let b = [a[0] * x[0], a[1] * x[1]];
+    ///
+    /// let solution = MatrixBatch::from_matrices(&a)
+    ///     .solve(&MatrixBatch::from_matrices(&b))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(solution.to_matrices(), x);
+    ///
+    /// let singular = MatrixBatch::from_matrices(&[a[0], SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]])]);
+    /// assert_eq!(singular.solve(&MatrixBatch::from_matrices(&b)), None);
+    /// ```
+    pub fn solve<const P: usize>(
+        &self,
+        rhs: &MatrixBatch<B, N, P, T>,
+    ) -> Option<MatrixBatch<B, N, P, T>> {
+        let mut a = self.data;
+        let mut x = rhs.data;
+        for k in 0..N {
+            for lane in 0..B {
+                let pivot = (k..N)
+                    .max_by(|&i, &j| {
+                        a[i][k][lane]
+                            .abs()
+                            .partial_cmp(&a[j][k][lane].abs())
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .unwrap_or(k);
+                if a[pivot][k][lane].is_zero() {
+                    return None;
+                }
+                if pivot != k {
+                    swap_lane(&mut a, k, pivot, lane);
+                    swap_lane(&mut x, k, pivot, lane);
+                }
+            }
+            let (above, below) = a.split_at_mut(k + 1);
+            let (x_above, x_below) = x.split_at_mut(k + 1);
+            let (pivot_row, x_pivot_row) = (&above[k], &x_above[k]);
+            for (row, x_row) in below.iter_mut().zip(x_below.iter_mut()) {
+                let multipliers: [T; B] =
+                    std::array::from_fn(|lane| row[k][lane] / pivot_row[k][lane]);
+                eliminate(row, pivot_row, &multipliers);
+                eliminate(x_row, x_pivot_row, &multipliers);
+            }
+        }
+        for k in (0..N).rev() {
+            let (above, solved) = x.split_at_mut(k + 1);
+            let x_row = &mut above[k];
+            for (multipliers, solved_row) in a[k].iter().skip(k + 1).zip(solved.iter()) {
+                eliminate(x_row, solved_row, multipliers);
+            }
+            for x_lanes in x_row.iter_mut() {
+                for (entry, pivot) in x_lanes.iter_mut().zip(a[k][k]) {
+                    *entry = *entry / pivot;
+                }
+            }
+        }
+        Some(MatrixBatch { data: x })
+    }
+}
+
+/// Subtract `multipliers` times `pivot_row` from `row`, lane by lane.
+fn eliminate<const B: usize, const C: usize, T: MatrixEntry + Float>(
+    row: &mut [[T; B]; C],
+    pivot_row: &[[T; B]; C],
+    multipliers: &[T; B],
+) {
+    for (lanes, pivot_lanes) in row.iter_mut().zip(pivot_row) {
+        for ((entry, pivot_entry), multiplier) in lanes.iter_mut().zip(pivot_lanes).zip(multipliers)
+        {
+            *entry = *entry - *multiplier * *pivot_entry;
+        }
+    }
+}
+
+/// Swap rows `i` and `j`, where `i < j`, of the matrix in lane `lane` only.
+fn swap_lane<const B: usize, const R: usize, const C: usize, T: MatrixEntry>(
+    rows: &mut [[[T; B]; C]; R],
+    i: usize,
+    j: usize,
+    lane: usize,
+) {
+    let (upper, lower) = rows.split_at_mut(j);
+    for (a, b) in upper[i].iter_mut().zip(lower[0].iter_mut()) {
+        std::mem::swap(&mut a[lane], &mut b[lane]);
+    }
+}
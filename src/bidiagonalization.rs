@@ -0,0 +1,98 @@
+use num_traits::{Float, One};
+
+use crate::{dim, Matrix, MatrixEntry};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, T> {
+    /// The Golub–Kahan bidiagonalization `A = UBVᵀ` of `self`, returned as `(U, B, V)`,
+    /// where `U` and `V` are orthogonal and `B` is upper bidiagonal.
+    /// Entries are annihilated one at a time with Givens rotations.
+    ///
+    /// `M` must be at least `N`, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<4,3,f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0], [1.0, 0.0, 1.0]]);
+    ///
+    /// let (u, b, v) = a.bidiagonalize();
+    ///
+    /// for (i, row) in b.as_slice().iter().enumerate() {
+    ///     for (j, entry) in row.iter().enumerate() {
+    ///         if j != i && j != i + 1 {
+    ///             assert!(entry.abs() < 1e-12);
+    ///         }
+    ///     }
+    /// }
+    /// assert!((u * b * v.transpose() - a).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn bidiagonalize(&self) -> (Matrix<M, M, T>, Matrix<M, N, T>, Matrix<N, N, T>) {
+        const {
+            dim::assert_at_least(
+                M,
+                N,
+                "bidiagonalization needs at least as many rows as columns",
+            )
+        };
+        let mut u = Matrix::<M, M, T>::one();
+        let mut b = *self;
+        let mut v = Matrix::<N, N, T>::one();
+        for k in 0..N {
+            for i in k + 1..M {
+                if let Some((c, s)) = givens(b.data[k][k], b.data[i][k]) {
+                    rotate_rows(&mut b, k, i, c, s);
+                    rotate_cols(&mut u, k, i, c, s);
+                    b.data[i][k] = T::zero();
+                }
+            }
+            for j in k + 2..N {
+                if let Some((c, s)) = givens(b.data[k][k + 1], b.data[k][j]) {
+                    rotate_cols(&mut b, k + 1, j, c, s);
+                    rotate_cols(&mut v, k + 1, j, c, s);
+                    b.data[k][j] = T::zero();
+                }
+            }
+        }
+        (u, b, v)
+    }
+}
+
+/// The cosine and sine `(c, s)` of the Givens rotation taking `(a, b)` to `(r, 0)`.
+/// If `b` is already zero, get [`None`] instead.
+pub(crate) fn givens<T: Float>(a: T, b: T) -> Option<(T, T)> {
+    if b.is_zero() {
+        return None;
+    }
+    let r = a.hypot(b);
+    Some((a / r, b / r))
+}
+
+/// Replace rows `i` and `j` of `a` with `c·rᵢ + s·rⱼ` and `c·rⱼ - s·rᵢ`.
+pub(crate) fn rotate_rows<const M: usize, const N: usize, T: MatrixEntry + Float>(
+    a: &mut Matrix<M, N, T>,
+    i: usize,
+    j: usize,
+    c: T,
+    s: T,
+) {
+    for col in 0..N {
+        let (x, y) = (a.data[i][col], a.data[j][col]);
+        a.data[i][col] = c * x + s * y;
+        a.data[j][col] = c * y - s * x;
+    }
+}
+
+/// Replace columns `i` and `j` of `a` with `c·cᵢ + s·cⱼ` and `c·cⱼ - s·cᵢ`.
+pub(crate) fn rotate_cols<const M: usize, const N: usize, T: MatrixEntry + Float>(
+    a: &mut Matrix<M, N, T>,
+    i: usize,
+    j: usize,
+    c: T,
+    s: T,
+) {
+    for row in a.data.iter_mut() {
+        let (x, y) = (row[i], row[j]);
+        row[i] = c * x + s * y;
+        row[j] = c * y - s * x;
+    }
+}
@@ -0,0 +1,109 @@
+use num_traits::Float;
+
+use crate::{ColumnVector, Matrix, MatrixEntry, SquareMatrix};
+
+/// The Jacobian matrix of `f` at `x`, whose entry `(i, j)` is the partial derivative `∂fᵢ/∂xⱼ`, estimated by central
+/// differences `(f(x + εeⱼ) - f(x - εeⱼ)) / 2ε` with step `eps`.
+///
+/// Together with [`Matrix::least_squares`] this gives the Gauss–Newton step, solving `Jδ ≈ -f(x)`.
+/// The error of each entry is of order `ε²` from truncation plus `u/ε` from rounding, with `u` the unit roundoff,
+/// so a step near the cube root of `u` balances the two.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{numerical_jacobian, Matrix};
+/// let polar_to_cartesian = |[r, theta]: &[f64; 2]| [r * theta.cos(), r * theta.sin()];
+///
+/// let jacobian = numerical_jacobian(polar_to_cartesian, &[2.0, 0.0], 1e-5);
+///
+/// assert!((jacobian - Matrix::new([[1.0, 0.0], [0.0, 2.0]])).frobenius_norm() < 1e-9);
+/// ```
+pub fn numerical_jacobian<const M: usize, const N: usize, T: MatrixEntry + Float>(
+    f: impl Fn(&[T; N]) -> [T; M],
+    x: &[T; N],
+    eps: T,
+) -> Matrix<M, N, T> {
+    let two = T::one() + T::one();
+    let mut jacobian = Matrix::<M, N, T>::new([[T::zero(); N]; M]);
+    for j in 0..N {
+        let (mut forward, mut backward) = (*x, *x);
+        forward[j] = forward[j] + eps;
+        backward[j] = backward[j] - eps;
+        let (f_forward, f_backward) = (f(&forward), f(&backward));
+        for (row, (above, below)) in jacobian
+            .data
+            .iter_mut()
+            .zip(f_forward.iter().zip(f_backward))
+        {
+            row[j] = (*above - below) / (two * eps);
+        }
+    }
+    jacobian
+}
+
+/// The gradient of the scalar function `f` at `x`, the column of partial derivatives `∂f/∂xⱼ`, estimated by central
+/// differences with step `eps` as in [`numerical_jacobian`].
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{numerical_gradient, ColumnVector};
+/// let f = |[x, y]: &[f64; 2]| x * x * y + y.exp();
+///
+/// let gradient = numerical_gradient(f, &[1.0, 0.0], 1e-5);
+///
+/// assert!((gradient - ColumnVector::new([[0.0], [2.0]])).frobenius_norm() < 1e-9);
+/// ```
+pub fn numerical_gradient<const N: usize, T: MatrixEntry + Float>(
+    f: impl Fn(&[T; N]) -> T,
+    x: &[T; N],
+    eps: T,
+) -> ColumnVector<N, T> {
+    numerical_jacobian(|x| [f(x)], x, eps).transpose()
+}
+
+/// The Hessian matrix of the scalar function `f` at `x`, whose entry `(i, j)` is the second partial derivative
+/// `∂²f/∂xᵢ∂xⱼ`, estimated by central differences
+/// `(f(x + εeᵢ + εeⱼ) - f(x + εeᵢ - εeⱼ) - f(x - εeᵢ + εeⱼ) + f(x - εeᵢ - εeⱼ)) / 4ε²` with step `eps`.
+///
+/// Each entry above the diagonal is estimated once and mirrored, so the result is exactly symmetric. Rounding errors
+/// are of order `u/ε²`, so the step should be larger than for [`numerical_jacobian`], near the fourth root of the
+/// unit roundoff `u`.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{numerical_hessian, SquareMatrix};
+/// let f = |[x, y]: &[f64; 2]| x * x * y + 3.0 * y * y;
+///
+/// let hessian = numerical_hessian(f, &[1.0, 2.0], 1e-4);
+///
+/// assert!((hessian - SquareMatrix::new([[4.0, 2.0], [2.0, 6.0]])).frobenius_norm() < 1e-6);
+/// assert_eq!(hessian, hessian.transpose());
+/// ```
+pub fn numerical_hessian<const N: usize, T: MatrixEntry + Float>(
+    f: impl Fn(&[T; N]) -> T,
+    x: &[T; N],
+    eps: T,
+) -> SquareMatrix<N, T> {
+    let four = T::one() + T::one() + T::one() + T::one();
+    let shifted = |i: usize, step_i: T, j: usize, step_j: T| {
+        let mut point = *x;
+        point[i] = point[i] + step_i;
+        point[j] = point[j] + step_j;
+        f(&point)
+    };
+    let mut hessian = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+    for i in 0..N {
+        for j in i..N {
+            let second_difference =
+                shifted(i, eps, j, eps) - shifted(i, eps, j, -eps) - shifted(i, -eps, j, eps)
+                    + shifted(i, -eps, j, -eps);
+            let entry = second_difference / (four * eps * eps);
+            hessian.data[i][j] = entry;
+            hessian.data[j][i] = entry;
+        }
+    }
+    hessian
+}
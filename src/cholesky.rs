@@ -0,0 +1,114 @@
+use num_traits::{Float, Zero};
+
+use crate::{
+    back_substitution, forward_substitution, Factorization, Matrix, MatrixEntry, SquareMatrix,
+};
+
+/// Cholesky factorization `A = LLᵀ` of a symmetric positive definite `N`-by-`N` matrix `A`,
+/// where `L` is lower triangular with a positive diagonal.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Cholesky<const N: usize, T: MatrixEntry> {
+    l: SquareMatrix<N, T>,
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Cholesky<N, T> {
+    /// Factorize the symmetric matrix `a`, reading only its lower triangle.
+    /// If `a` is not positive definite, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Cholesky, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[4.0, 2.0], [2.0, 5.0]]);
+    /// let cholesky = Cholesky::new(&a).unwrap();
+    /// assert_eq!(*cholesky.get_l(), SquareMatrix::<2,f64>::new([[2.0, 0.0], [1.0, 2.0]]));
+    /// ```
+    ///
+    /// ```
+    /// # use malg::{Cholesky, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 1.0]]);
+    /// assert!(Cholesky::new(&a).is_none());
+    /// ```
+    pub fn new(a: &SquareMatrix<N, T>) -> Option<Cholesky<N, T>> {
+        let mut l = SquareMatrix::<N, T>::zero();
+        for j in 0..N {
+            let mut diagonal = a.data[j][j];
+            for k in 0..j {
+                diagonal = diagonal - l.data[j][k] * l.data[j][k];
+            }
+            if diagonal <= T::zero() || diagonal.is_nan() {
+                return None;
+            }
+            l.data[j][j] = diagonal.sqrt();
+            for i in j + 1..N {
+                let mut sum = a.data[i][j];
+                for k in 0..j {
+                    sum = sum - l.data[i][k] * l.data[j][k];
+                }
+                l.data[i][j] = sum / l.data[j][j];
+            }
+        }
+        Some(Cholesky::<N, T> { l })
+    }
+    /// Lower triangular factor `L`.
+    pub fn get_l(&self) -> &SquareMatrix<N, T> {
+        &self.l
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Factorization<N> for Cholesky<N, T> {
+    type Output = T;
+    /// Solve `Ax = b` by forward substitution of `Ly = b` and back substitution of `Lᵀx = y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Cholesky, Factorization, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[4.0, 2.0], [2.0, 5.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[6.0], [7.0]]);
+    /// let x = Cholesky::new(&a).unwrap().solve_column(&b).unwrap();
+    /// assert_eq!(x, Matrix::<2,1,f64>::new([[1.0], [1.0]]));
+    /// ```
+    fn solve_column(&self, b: &Matrix<N, 1, T>) -> Option<Matrix<N, 1, T>> {
+        let y = forward_substitution(&self.l, b, false)?;
+        back_substitution(&self.l.transpose(), &y)
+    }
+    /// The determinant of `A`, the squared product of the diagonal of `L`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Cholesky, Factorization, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[4.0, 2.0], [2.0, 5.0]]);
+    /// assert_eq!(Cholesky::new(&a).unwrap().det(), 16.0);
+    /// ```
+    fn det(&self) -> T {
+        let product = (0..N).fold(T::one(), |det, i| det * self.l.data[i][i]);
+        product * product
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The lower triangular Cholesky factor `L` of a symmetric positive definite matrix, with `A = LLᵀ`,
+    /// reading only the lower triangle of `self`. If `self` is not positive definite, get [`None`] instead.
+    ///
+    /// To reuse the factorization for solves, use [`Cholesky`] directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let covariance = SquareMatrix::<3,f64>::new([[4.0, 2.0, 2.0], [2.0, 5.0, 3.0], [2.0, 3.0, 6.0]]);
+    ///
+    /// let l = covariance.cholesky().unwrap();
+    ///
+    /// assert_eq!(l, SquareMatrix::new([[2.0, 0.0, 0.0], [1.0, 2.0, 0.0], [1.0, 1.0, 2.0]]));
+    /// assert_eq!(l * l.transpose(), covariance);
+    ///
+    /// let indefinite = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 1.0]]);
+    /// assert_eq!(indefinite.cholesky(), None);
+    /// ```
+    pub fn cholesky(&self) -> Option<SquareMatrix<N, T>> {
+        Cholesky::new(self).map(|cholesky| cholesky.l)
+    }
+}
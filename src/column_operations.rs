@@ -0,0 +1,15 @@
+use std::ops::{Add, Mul};
+
+use crate::MatrixEntry;
+
+/// Provides a set of elementary column operations for an object, where elements of the object are scaled by type `Scalar`
+pub trait ColOps<Scalar: MatrixEntry + Mul<Output = Scalar> + Add<Output = Scalar>> {
+    /// Swap columns `i` and `j` in place.
+    fn swap_cols(&mut self, i: usize, j: usize);
+    /// Scale column `i` by scalar value `a` in place.
+    fn scale_col(&mut self, i: usize, a: Scalar);
+    /// Replace column `i` with the sum of column `i` and `a` times column `j`.
+    fn add_cols(&mut self, i: usize, j: usize, a: Scalar);
+    /// The `i`th column of `self`.
+    fn get_col(&self, i: usize) -> Vec<Scalar>;
+}
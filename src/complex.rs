@@ -0,0 +1,260 @@
+use num_complex::Complex;
+use num_traits::{Float, One, Zero};
+
+use crate::{Matrix, MatrixEntry, MatrixError, SquareMatrix};
+
+/// Maximum number of Jacobi sweeps before giving up on a unitary diagonalization.
+const MAX_SWEEPS: usize = 100;
+
+/// A unitary diagonalization `A = VΛVᴴ` of a normal matrix `A`, with the eigenvalues on the diagonal of `Λ`
+/// and an orthonormal basis of eigenvectors in the columns of the unitary matrix `V`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitaryDiagonalization<const N: usize, T: MatrixEntry> {
+    eigenvalues: [Complex<T>; N],
+    eigenvectors: SquareMatrix<N, Complex<T>>,
+}
+
+impl<const N: usize, T: MatrixEntry> UnitaryDiagonalization<N, T> {
+    /// The eigenvalues, in the order of the columns of [`get_eigenvectors`](Self::get_eigenvectors).
+    pub fn get_eigenvalues(&self) -> &[Complex<T>; N] {
+        &self.eigenvalues
+    }
+
+    /// The unitary matrix `V` whose columns are orthonormal eigenvectors.
+    pub fn get_eigenvectors(&self) -> &SquareMatrix<N, Complex<T>> {
+        &self.eigenvectors
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, Complex<T>> {
+    /// The conjugate transpose `Aᴴ` of a matrix of complex numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// # use num_complex::Complex;
+    /// let a = Matrix::<1,2,Complex<f64>>::new([[Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]]);
+    /// let a_h = Matrix::new([[Complex::new(1.0, -2.0)], [Complex::new(0.0, 1.0)]]);
+    /// assert_eq!(a.conjugate_transpose(), a_h);
+    /// ```
+    pub fn conjugate_transpose(&self) -> Matrix<N, M, Complex<T>> {
+        let mut conjugated = self.transpose();
+        for entry in conjugated.data.iter_mut().flatten() {
+            *entry = entry.conj();
+        }
+        conjugated
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, Complex<T>> {
+    /// Whether `self` is normal, commuting with its conjugate transpose, to within a tolerance relative
+    /// to its size: `‖AAᴴ - AᴴA‖ <= tol * ‖A‖²` in the Frobenius norm.
+    ///
+    /// Hermitian, skew-Hermitian and unitary matrices are all normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// # use num_complex::Complex;
+    /// let rotation = SquareMatrix::<2,Complex<f64>>::new([
+    ///     [Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0)],
+    ///     [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+    /// ]);
+    /// let shear = SquareMatrix::<2,Complex<f64>>::new([
+    ///     [Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)],
+    ///     [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+    /// ]);
+    /// assert!(rotation.is_normal(1e-12));
+    /// assert!(!shear.is_normal(1e-12));
+    /// ```
+    pub fn is_normal(&self, tol: T) -> bool {
+        let a_h = self.conjugate_transpose();
+        let norm = frobenius_norm(self);
+        frobenius_norm(&(*self * a_h - a_h * *self)) <= tol * norm * norm
+    }
+
+    /// The unitary diagonalization `A = VΛVᴴ` of a normal matrix `A`, with eigenvalues `Λ` and a unitary eigenbasis `V`.
+    ///
+    /// The Hermitian and skew-Hermitian parts of a normal matrix commute, so they share an eigenbasis. It is found by
+    /// diagonalizing a generic combination of the two with complex Jacobi rotations, and each eigenvalue is then read off
+    /// as the Rayleigh quotient of its eigenvector.
+    ///
+    /// If `self` is not [normal](Self::is_normal) to within the square root of machine epsilon, get [`MatrixError::NotNormal`] instead,
+    /// or if the rotations fail to diagonalize it, get [`MatrixError::NotConverged`].
+    ///
+    /// # Examples
+    ///
+    /// A real rotation by a quarter turn has eigenvalues `±i`.
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// # use num_complex::Complex;
+    /// # use num_traits::One;
+    /// let a = SquareMatrix::<2,Complex<f64>>::new([
+    ///     [Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0)],
+    ///     [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+    /// ]);
+    ///
+    /// let diagonalization = a.diagonalize_normal().unwrap();
+    /// let v = *diagonalization.get_eigenvectors();
+    ///
+    /// let mut eigenvalues = diagonalization.get_eigenvalues().map(|lambda| lambda.im);
+    /// eigenvalues.sort_by(f64::total_cmp);
+    /// assert!((eigenvalues[0] + 1.0).abs() < 1e-12 && (eigenvalues[1] - 1.0).abs() < 1e-12);
+    /// let v_h_v = v.conjugate_transpose() * v - SquareMatrix::one();
+    /// assert!(v_h_v.as_slice().iter().flatten().all(|e| e.norm() < 1e-12));
+    /// for (j, lambda) in diagonalization.get_eigenvalues().iter().enumerate() {
+    ///     let v_j = Matrix::<2,1,Complex<f64>>::new([[v.as_slice()[0][j]], [v.as_slice()[1][j]]]);
+    ///     let residual = a * v_j - v_j * Matrix::new([[*lambda]]);
+    ///     assert!(residual.as_slice().iter().flatten().all(|e| e.norm() < 1e-12));
+    /// }
+    /// ```
+    pub fn diagonalize_normal(&self) -> Result<UnitaryDiagonalization<N, T>, MatrixError> {
+        let tol = T::epsilon().sqrt();
+        if !self.is_normal(tol) {
+            return Err(MatrixError::NotNormal);
+        }
+        let two = T::one() + T::one();
+        // Hermitian part H = (A + Aᴴ)/2 and skew-Hermitian part iK = (A - Aᴴ)/2 with K Hermitian,
+        // combined with an irrational weight so that distinct eigenvalues of A stay distinct.
+        let weight = (T::from(5.0).unwrap_or_else(T::one).sqrt() - T::one()) / two;
+        let a_h = self.conjugate_transpose();
+        let mut combined = *self;
+        for ((entry, a), a_h) in combined
+            .data
+            .iter_mut()
+            .flatten()
+            .zip(self.data.iter().flatten())
+            .zip(a_h.data.iter().flatten())
+        {
+            let hermitian = (*a + *a_h).unscale(two);
+            let skew = (*a - *a_h).unscale(two) * Complex::new(T::zero(), -T::one());
+            *entry = hermitian + skew.scale(weight);
+        }
+        let v = hermitian_jacobi(combined).ok_or(MatrixError::NotConverged)?;
+        let lambda = v.conjugate_transpose() * *self * v;
+        let mut eigenvalues = [Complex::zero(); N];
+        let mut off_diagonal = T::zero();
+        for (i, row) in lambda.data.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                if i == j {
+                    eigenvalues[i] = *entry;
+                } else {
+                    off_diagonal = off_diagonal + entry.norm_sqr();
+                }
+            }
+        }
+        if off_diagonal.sqrt() > tol * frobenius_norm(self) {
+            return Err(MatrixError::NotConverged);
+        }
+        Ok(UnitaryDiagonalization {
+            eigenvalues,
+            eigenvectors: v,
+        })
+    }
+}
+
+/// The Frobenius norm of a complex matrix.
+fn frobenius_norm<const M: usize, const N: usize, T: MatrixEntry + Float>(
+    a: &Matrix<M, N, Complex<T>>,
+) -> T {
+    a.data
+        .iter()
+        .flatten()
+        .fold(T::zero(), |sum, entry| sum + entry.norm_sqr())
+        .sqrt()
+}
+
+/// The unitary matrix `V` diagonalizing the Hermitian matrix `a` as `Vᴴ a V`, found by cyclic complex Jacobi rotations.
+/// If the off-diagonal entries are not negligible after [`MAX_SWEEPS`] sweeps, get [`None`] instead.
+fn hermitian_jacobi<const N: usize, T: MatrixEntry + Float>(
+    mut a: SquareMatrix<N, Complex<T>>,
+) -> Option<SquareMatrix<N, Complex<T>>> {
+    let two = T::one() + T::one();
+    let scale = frobenius_norm(&a);
+    let mut v = SquareMatrix::<N, Complex<T>>::new([[Complex::zero(); N]; N]);
+    for (i, row) in v.data.iter_mut().enumerate() {
+        row[i] = Complex::one();
+    }
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..N)
+            .flat_map(|i| (0..N).filter(move |&j| j != i).map(move |j| (i, j)))
+            .fold(T::zero(), |sum, (i, j)| sum + a.data[i][j].norm_sqr());
+        if off_diagonal.sqrt() <= T::epsilon() * scale {
+            return Some(v);
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                let magnitude = a.data[p][q].norm();
+                if magnitude.is_zero() {
+                    continue;
+                }
+                // Rotate the phase of a_pq away, then zero it with a real Jacobi rotation.
+                let phase = (a.data[p][q] / magnitude).conj();
+                let theta = (a.data[q][q].re - a.data[p][p].re) / (two * magnitude);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = (t * t + T::one()).sqrt().recip();
+                let s = t * c;
+                let rotation = [
+                    [Complex::from(c), Complex::from(s)],
+                    [phase.scale(-s), phase.scale(c)],
+                ];
+                for row in a.data.iter_mut().chain(v.data.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = x * rotation[0][0] + y * rotation[1][0];
+                    row[q] = x * rotation[0][1] + y * rotation[1][1];
+                }
+                for k in 0..N {
+                    let (x, y) = (a.data[p][k], a.data[q][k]);
+                    a.data[p][k] = rotation[0][0].conj() * x + rotation[1][0].conj() * y;
+                    a.data[q][k] = rotation[0][1].conj() * x + rotation[1][1].conj() * y;
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use num_complex::Complex;
+
+    use crate::*;
+
+    /// Check a complex circulant [`Matrix`] is rebuilt from its unitary diagonalization
+    #[test]
+    fn check_circulant_diagonalization() -> Result<(), Box<dyn Error>> {
+        let c = |re, im| Complex::new(re, im);
+        let (x, y, z) = (c(1.0, 2.0), c(-0.5, 1.0), c(3.0, 0.0));
+        let a = SquareMatrix::<3, Complex<f64>>::new([[x, y, z], [z, x, y], [y, z, x]]);
+
+        let diagonalization = a.diagonalize_normal()?;
+        let v = *diagonalization.get_eigenvectors();
+        let mut lambda = SquareMatrix::<3, Complex<f64>>::new([[c(0.0, 0.0); 3]; 3]);
+        for (i, eigenvalue) in diagonalization.get_eigenvalues().iter().enumerate() {
+            lambda.data[i][i] = *eigenvalue;
+        }
+
+        let error = v * lambda * v.conjugate_transpose() - a;
+        assert!(error.as_slice().iter().flatten().all(|e| e.norm() < 1e-12));
+        let trace = diagonalization
+            .get_eigenvalues()
+            .iter()
+            .fold(c(0.0, 0.0), |sum, e| sum + e);
+        assert!((trace - x * 3.0).norm() < 1e-12);
+        Ok(())
+    }
+
+    /// Check a non-normal [`Matrix`] is rejected
+    #[test]
+    fn check_non_normal_rejected() -> Result<(), Box<dyn Error>> {
+        let one = Complex::new(1.0, 0.0);
+        let a = SquareMatrix::<2, Complex<f64>>::new([[one, one], [Complex::new(0.0, 0.0), one]]);
+        assert_eq!(a.diagonalize_normal(), Err(MatrixError::NotNormal));
+        Ok(())
+    }
+}
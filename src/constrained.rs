@@ -0,0 +1,147 @@
+use std::ops::Mul;
+
+use num_traits::Float;
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+/// Column stochastic `N`-by-`N` matrix, whose entries are non-negative and whose columns sum to one,
+/// such as the transition matrix of a Markov chain where entry `(i, j)` is the probability of moving from state `j`
+/// to state `i`.
+///
+/// The invariant is checked on construction, and the only operations offered preserve it.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{Matrix, ProbabilityMatrix, SquareMatrix};
+/// let weather = ProbabilityMatrix::new(
+///     SquareMatrix::<2,f64>::new([[0.75, 0.5], [0.25, 0.5]]),
+///     1e-12,
+/// )
+/// .unwrap();
+///
+/// let two_days = weather * weather;
+/// let sunny = Matrix::<2,1,f64>::new([[1.0], [0.0]]);
+/// assert_eq!(two_days.step(&sunny), Matrix::new([[0.6875], [0.3125]]));
+///
+/// assert!(ProbabilityMatrix::new(SquareMatrix::<2,f64>::new([[0.5, 0.5], [0.25, 0.5]]), 1e-12).is_none());
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct ProbabilityMatrix<const N: usize, T: MatrixEntry> {
+    matrix: SquareMatrix<N, T>,
+}
+
+impl<const N: usize, T: MatrixEntry + Float> ProbabilityMatrix<N, T> {
+    /// Wrap `matrix`, checking that it is [column stochastic](SquareMatrix::is_column_stochastic) with column sums
+    /// within `tol` of one. If it is not, get [`None`] instead.
+    pub fn new(matrix: SquareMatrix<N, T>, tol: T) -> Option<Self> {
+        if matrix.is_column_stochastic(tol) {
+            Some(ProbabilityMatrix { matrix })
+        } else {
+            None
+        }
+    }
+    /// The underlying matrix.
+    pub fn get_matrix(&self) -> &SquareMatrix<N, T> {
+        &self.matrix
+    }
+    /// The distribution after one transition from the distribution `p`, the product `Pp`.
+    pub fn step(&self, p: &Matrix<N, 1, T>) -> Matrix<N, 1, T> {
+        self.matrix * *p
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Mul for ProbabilityMatrix<N, T> {
+    type Output = Self;
+    /// Compose two transitions, as a product of column stochastic matrices is column stochastic.
+    fn mul(self, rhs: Self) -> Self {
+        ProbabilityMatrix {
+            matrix: self.matrix * rhs.matrix,
+        }
+    }
+}
+
+/// Correlation matrix, a symmetric positive semidefinite `N`-by-`N` matrix with ones on the diagonal,
+/// so that every entry lies between `-1` and `1`.
+///
+/// The invariant is checked on construction, and the only operations offered preserve it.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{CorrelationMatrix, SquareMatrix};
+/// let covariance = SquareMatrix::<2,f64>::new([[4.0, 3.0], [3.0, 9.0]]);
+/// let correlation = CorrelationMatrix::from_covariance(&covariance).unwrap();
+/// assert_eq!(*correlation.get_matrix(), SquareMatrix::new([[1.0, 0.5], [0.5, 1.0]]));
+/// assert_eq!(correlation.to_covariance(&[2.0, 3.0]), covariance);
+///
+/// let impossible = SquareMatrix::<3,f64>::new([[1.0, 0.9, -0.9], [0.9, 1.0, 0.9], [-0.9, 0.9, 1.0]]);
+/// assert!(CorrelationMatrix::new(impossible, 1e-12).is_none());
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct CorrelationMatrix<const N: usize, T: MatrixEntry> {
+    matrix: SquareMatrix<N, T>,
+}
+
+impl<const N: usize, T: MatrixEntry + Float> CorrelationMatrix<N, T> {
+    /// Wrap `matrix`, checking that it is symmetric and has ones on the diagonal to within `tol`, and that its
+    /// [eigenvalues](SquareMatrix::symmetric_eigen) are at least `-tol`. If it is not, get [`None`] instead.
+    pub fn new(matrix: SquareMatrix<N, T>, tol: T) -> Option<Self> {
+        let data = matrix.as_slice();
+        let symmetric = (0..N).all(|i| (0..i).all(|j| (data[i][j] - data[j][i]).abs() <= tol));
+        let unit_diagonal = (0..N).all(|i| (data[i][i] - T::one()).abs() <= tol);
+        if !symmetric || !unit_diagonal {
+            return None;
+        }
+        let eigen = matrix.symmetric_eigen().ok()?;
+        if eigen.get_eigenvalues().iter().any(|lambda| *lambda < -tol) {
+            return None;
+        }
+        Some(CorrelationMatrix { matrix })
+    }
+    /// The correlation matrix `D⁻¹CD⁻¹` of the covariance matrix `covariance`, where `D` is the diagonal matrix of
+    /// standard deviations. If a variance is not positive, or the result fails the checks of
+    /// [`CorrelationMatrix::new`] with a tolerance of `Nε`, because `covariance` is not symmetric or not positive
+    /// semidefinite, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{CorrelationMatrix, SquareMatrix};
+    /// let impossible = SquareMatrix::<3,f64>::new([[1.0, 0.9, -0.9], [0.9, 1.0, 0.9], [-0.9, 0.9, 1.0]]);
+    /// assert!(CorrelationMatrix::from_covariance(&(impossible * 4.0)).is_none());
+    ///
+    /// let asymmetric = SquareMatrix::<2,f64>::new([[4.0, 1.0], [3.0, 9.0]]);
+    /// assert!(CorrelationMatrix::from_covariance(&asymmetric).is_none());
+    /// ```
+    pub fn from_covariance(covariance: &SquareMatrix<N, T>) -> Option<Self> {
+        let data = covariance.as_slice();
+        if (0..N).any(|i| data[i][i] <= T::zero() || data[i][i].is_nan()) {
+            return None;
+        }
+        let std_devs: [T; N] = std::array::from_fn(|i| data[i][i].sqrt());
+        let matrix = SquareMatrix::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                if i == j {
+                    T::one()
+                } else {
+                    (data[i][j] / (std_devs[i] * std_devs[j]))
+                        .max(-T::one())
+                        .min(T::one())
+                }
+            })
+        }));
+        CorrelationMatrix::new(matrix, T::from(N).unwrap_or_else(T::one) * T::epsilon())
+    }
+    /// The underlying matrix.
+    pub fn get_matrix(&self) -> &SquareMatrix<N, T> {
+        &self.matrix
+    }
+    /// The covariance matrix `DCD` of variables with this correlation and the standard deviations `std_devs`.
+    pub fn to_covariance(&self, std_devs: &[T; N]) -> SquareMatrix<N, T> {
+        let data = self.matrix.as_slice();
+        SquareMatrix::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| data[i][j] * std_devs[i] * std_devs[j])
+        }))
+    }
+}
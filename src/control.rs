@@ -0,0 +1,202 @@
+use num_traits::{Float, One};
+
+use crate::{
+    matrix_equation::solve_dense, Factorization, Lu, Matrix, MatrixEntry, MatrixError, SquareMatrix,
+};
+
+/// Solve the discrete Lyapunov equation `X = AXAᵀ + Q` for `X`.
+///
+/// The equation is rewritten as the `N²`-by-`N²` linear system `(I - A⊗A) vec(X) = vec(Q)`, so this suits small matrices.
+/// If some product of two eigenvalues of `A` is one, the system is singular and [`MatrixError::Singular`] is returned.
+/// When `A` is stable and `Q` is symmetric positive semi-definite, `X` is the steady state covariance of `xₖ₊₁ = Axₖ + wₖ`
+/// with noise covariance `Q`.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{solve_discrete_lyapunov, SquareMatrix};
+/// let a = SquareMatrix::<2,f64>::new([[0.5, 0.1], [0.0, 0.8]]);
+/// let q = SquareMatrix::<2,f64>::new([[1.0, 0.0], [0.0, 2.0]]);
+///
+/// let x = solve_discrete_lyapunov(&a, &q).unwrap();
+///
+/// let residual = a * x * a.transpose() + q - x;
+/// assert!(residual.frobenius_norm() < 1e-12);
+/// ```
+pub fn solve_discrete_lyapunov<const N: usize, T: MatrixEntry + Float>(
+    a: &SquareMatrix<N, T>,
+    q: &SquareMatrix<N, T>,
+) -> Result<SquareMatrix<N, T>, MatrixError> {
+    let mut system = vec![vec![T::zero(); N * N + 1]; N * N];
+    for i in 0..N {
+        for j in 0..N {
+            let row = &mut system[i * N + j];
+            for k in 0..N {
+                for l in 0..N {
+                    row[k * N + l] = T::zero() - a.data[i][k] * a.data[j][l];
+                }
+            }
+            row[i * N + j] = row[i * N + j] + T::one();
+            row[N * N] = q.data[i][j];
+        }
+    }
+    let solution = solve_dense(system).ok_or(MatrixError::Singular)?;
+    let mut x = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+    for (entry, value) in x.data.iter_mut().flatten().zip(solution) {
+        *entry = value;
+    }
+    Ok(x)
+}
+
+/// Solve the discrete algebraic Riccati equation `X = AᵀXA - AᵀXB(R + BᵀXB)⁻¹BᵀXA + Q` for the stabilizing solution `X`.
+///
+/// The solution is found with the structure-preserving doubling algorithm, which converges quadratically when `(A, B)`
+/// is stabilizable, `(A, Q)` is detectable, `Q` is symmetric positive semi-definite and `R` is symmetric positive definite.
+/// Iteration stops once successive iterates agree to the relative tolerance `tol`. If `R` or an intermediate matrix is
+/// singular, get [`MatrixError::Singular`], and if the iterates have not converged after 100 doublings,
+/// get [`MatrixError::NotConverged`].
+///
+/// # Examples
+///
+/// A double integrator with unit state and control weights.
+///
+/// ```
+/// # use malg::{solve_discrete_riccati, Factorization, Lu, Matrix, SquareMatrix};
+/// let a = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 1.0]]);
+/// let b = Matrix::<2,1,f64>::new([[0.0], [1.0]]);
+/// let q = SquareMatrix::<2,f64>::new([[1.0, 0.0], [0.0, 1.0]]);
+/// let r = SquareMatrix::<1,f64>::new([[1.0]]);
+///
+/// let x = solve_discrete_riccati(&a, &b, &q, &r, 1e-14).unwrap();
+///
+/// let gain_term = a.transpose() * x * b * Lu::new(&(r + b.transpose() * x * b)).inverse().unwrap() * b.transpose() * x * a;
+/// let residual = a.transpose() * x * a - gain_term + q - x;
+/// assert!(residual.frobenius_norm() < 1e-10);
+/// ```
+pub fn solve_discrete_riccati<const N: usize, const K: usize, T: MatrixEntry + Float>(
+    a: &SquareMatrix<N, T>,
+    b: &Matrix<N, K, T>,
+    q: &SquareMatrix<N, T>,
+    r: &SquareMatrix<K, T>,
+    tol: T,
+) -> Result<SquareMatrix<N, T>, MatrixError> {
+    const MAX_DOUBLINGS: usize = 100;
+    let r_inv_b_t = Lu::new(r)
+        .solve(&b.transpose())
+        .ok_or(MatrixError::Singular)?;
+    let mut a_k = *a;
+    let mut g_k = *b * r_inv_b_t;
+    let mut h_k = *q;
+    for _ in 0..MAX_DOUBLINGS {
+        let w = Lu::new(&(SquareMatrix::one() + g_k * h_k));
+        let w_a = w.solve(&a_k).ok_or(MatrixError::Singular)?;
+        let w_g = w.solve(&g_k).ok_or(MatrixError::Singular)?;
+        let h_next = h_k + a_k.transpose() * h_k * w_a;
+        g_k = g_k + a_k * w_g * a_k.transpose();
+        a_k = a_k * w_a;
+        let change = (h_next - h_k).frobenius_norm();
+        h_k = h_next;
+        if change <= tol * h_k.frobenius_norm() {
+            return Ok(h_k);
+        }
+    }
+    Err(MatrixError::NotConverged)
+}
+
+/// The optimal state feedback gain `K = (R + BᵀXB)⁻¹BᵀXA` of the discrete time linear quadratic regulator,
+/// which minimizes `Σ xₖᵀQxₖ + uₖᵀRuₖ` for the system `xₖ₊₁ = Axₖ + Buₖ` under the control law `uₖ = -Kxₖ`.
+///
+/// `X` is the solution of the discrete algebraic Riccati equation from [`solve_discrete_riccati`].
+/// Since its doubling iteration converges quadratically, iteration stops once successive iterates agree to
+/// the square root of machine epsilon, by which point the solution is accurate to machine precision.
+/// Errors from solving the Riccati equation are passed on.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{lqr, Matrix, SquareMatrix};
+/// let a = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 1.0]]);
+/// let b = Matrix::<2,1,f64>::new([[0.0], [1.0]]);
+/// let q = SquareMatrix::<2,f64>::new([[1.0, 0.0], [0.0, 1.0]]);
+/// let r = SquareMatrix::<1,f64>::new([[1.0]]);
+///
+/// let k = lqr(&a, &b, &q, &r).unwrap();
+///
+/// // The closed loop system is stable, so the state decays to zero.
+/// let closed_loop = a - b * k;
+/// let mut x = Matrix::<2,1,f64>::new([[1.0], [-2.0]]);
+/// for _ in 0..100 {
+///     x = closed_loop * x;
+/// }
+/// assert!(x.frobenius_norm() < 1e-9);
+/// ```
+pub fn lqr<const N: usize, const K: usize, T: MatrixEntry + Float>(
+    a: &SquareMatrix<N, T>,
+    b: &Matrix<N, K, T>,
+    q: &SquareMatrix<N, T>,
+    r: &SquareMatrix<K, T>,
+) -> Result<Matrix<K, N, T>, MatrixError> {
+    let x = solve_discrete_riccati(a, b, q, r, T::epsilon().sqrt())?;
+    let b_t_x = b.transpose() * x;
+    Lu::new(&(*r + b_t_x * *b))
+        .solve(&(b_t_x * *a))
+        .ok_or(MatrixError::Singular)
+}
+
+/// The state feedback gain `K` placing the eigenvalues of `A - BK` at the real `poles`, for the single input system `xₖ₊₁ = Axₖ + Buₖ`.
+///
+/// The gain is found with Ackermann's formula `K = [0 ⋯ 0 1] C⁻¹ φ(A)`, where `C = [B AB ⋯ Aᴺ⁻¹B]` is the
+/// controllability matrix and `φ` is the monic polynomial with roots `poles`. If the system is not controllable,
+/// `C` is singular and [`MatrixError::Singular`] is returned.
+/// Ackermann's formula is numerically ill-conditioned for large `N`, so it suits small systems.
+///
+/// By duality, observer gains `L` placing the eigenvalues of `A - LC` are the transpose of the gain for `Aᵀ` and `Cᵀ`.
+///
+/// # Examples
+///
+/// ```
+/// # use num_traits::Zero;
+/// # use malg::{place_poles, Matrix, SquareMatrix};
+/// let a = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 1.0]]);
+/// let b = Matrix::<2,1,f64>::new([[0.0], [1.0]]);
+///
+/// let k = place_poles(&a, &b, &[0.5, 0.25]).unwrap();
+/// assert_eq!(k, Matrix::new([[0.375, 1.25]]));
+///
+/// // By the Cayley–Hamilton theorem, the closed loop matrix is a root of (x - 0.5)(x - 0.25).
+/// let closed_loop = a - b * k;
+/// assert_eq!(closed_loop.polyval(&[1.0, -0.75, 0.125]), SquareMatrix::zero());
+/// ```
+///
+/// An input that only drives the first state cannot place the poles.
+///
+/// ```
+/// # use malg::{place_poles, Matrix, MatrixError, SquareMatrix};
+/// let a = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 1.0]]);
+/// let b = Matrix::<2,1,f64>::new([[1.0], [0.0]]);
+/// assert_eq!(place_poles(&a, &b, &[0.5, 0.25]), Err(MatrixError::Singular));
+/// ```
+pub fn place_poles<const N: usize, T: MatrixEntry + Float>(
+    a: &SquareMatrix<N, T>,
+    b: &Matrix<N, 1, T>,
+    poles: &[T; N],
+) -> Result<Matrix<1, N, T>, MatrixError> {
+    let mut coeffs = vec![T::one()];
+    for pole in poles {
+        let mut next = coeffs.clone();
+        next.push(T::zero());
+        for (i, coeff) in coeffs.iter().enumerate() {
+            next[i + 1] = next[i + 1] - *pole * *coeff;
+        }
+        coeffs = next;
+    }
+    let controllability = a.krylov_basis::<N>(b);
+    let mut last = Matrix::<N, 1, T>::new([[T::zero()]; N]);
+    if let Some(entry) = last.data.last_mut() {
+        entry[0] = T::one();
+    }
+    let row = Lu::new(&controllability.transpose())
+        .solve_column(&last)
+        .ok_or(MatrixError::Singular)?;
+    Ok(row.transpose() * a.polyval(&coeffs))
+}
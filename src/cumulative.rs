@@ -0,0 +1,96 @@
+use std::ops::{Add, Mul};
+
+use crate::{Matrix, MatrixEntry};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Add<Output = T>> Matrix<M, N, T> {
+    /// The running sums along each row of `self`, so that entry `(i, j)` is the sum of the entries `(i, 0..=j)`,
+    /// such as an empirical distribution function from a row of probabilities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,u32>::new([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.cumsum_rows(), Matrix::new([[1, 3, 6], [4, 9, 15]]));
+    /// ```
+    ///
+    /// Running sums along both axes give the integral image, whose entry `(i, j)` is the sum of the block
+    /// `(0..=i, 0..=j)`.
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,u32>::new([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.cumsum_rows().cumsum_cols(), Matrix::new([[1, 3, 6], [5, 12, 21]]));
+    /// ```
+    pub fn cumsum_rows(&self) -> Self {
+        self.accumulate_rows(|total, entry| total + entry)
+    }
+
+    /// The running sums down each column of `self`, so that entry `(i, j)` is the sum of the entries `(0..=i, j)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,u32>::new([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a.cumsum_cols(), Matrix::new([[1, 2], [4, 6], [9, 12]]));
+    /// ```
+    pub fn cumsum_cols(&self) -> Self {
+        self.accumulate_cols(|total, entry| total + entry)
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Mul<Output = T>> Matrix<M, N, T> {
+    /// The running products along each row of `self`, so that entry `(i, j)` is the product of the entries
+    /// `(i, 0..=j)`, such as survival probabilities from a row of per-step survival rates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,u32>::new([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.cumprod_rows(), Matrix::new([[1, 2, 6], [4, 20, 120]]));
+    /// ```
+    pub fn cumprod_rows(&self) -> Self {
+        self.accumulate_rows(|total, entry| total * entry)
+    }
+
+    /// The running products down each column of `self`, so that entry `(i, j)` is the product of the entries
+    /// `(0..=i, j)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,u32>::new([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a.cumprod_cols(), Matrix::new([[1, 2], [3, 8], [15, 48]]));
+    /// ```
+    pub fn cumprod_cols(&self) -> Self {
+        self.accumulate_cols(|total, entry| total * entry)
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry> Matrix<M, N, T> {
+    /// Replace each entry after the first of each row of `self` by `combine` of the entry before it and itself.
+    fn accumulate_rows(&self, combine: impl Fn(T, T) -> T) -> Self {
+        let mut accumulated = *self;
+        for row in accumulated.data.iter_mut() {
+            for j in 1..N {
+                row[j] = combine(row[j - 1], row[j]);
+            }
+        }
+        accumulated
+    }
+
+    /// Replace each entry after the first of each column of `self` by `combine` of the entry above it and itself.
+    fn accumulate_cols(&self, combine: impl Fn(T, T) -> T) -> Self {
+        let mut accumulated = *self;
+        for i in 1..M {
+            let (above, below) = accumulated.data.split_at_mut(i);
+            for (entry, previous) in below[0].iter_mut().zip(above[i - 1]) {
+                *entry = combine(previous, *entry);
+            }
+        }
+        accumulated
+    }
+}
@@ -0,0 +1,252 @@
+use num_traits::{Float, One, Zero};
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+/// A factorization of an `N`-by-`N` matrix `A` with entries of type `Output`, which can be reused to
+/// solve linear systems `Ax = b` and compute properties of `A` without factorizing again.
+///
+/// Implemented by [`Lu`](crate::Lu), [`Qr`](crate::Qr) and [`Cholesky`](crate::Cholesky), so generic code can accept any of them.
+pub trait Factorization<const N: usize> {
+    /// Entry type of the factorized matrix.
+    type Output: MatrixEntry + Float;
+    /// Solve `Ax = b` for a single column `b`.
+    /// If `A` is singular, get [`None`] instead.
+    fn solve_column(&self, b: &Matrix<N, 1, Self::Output>) -> Option<Matrix<N, 1, Self::Output>>;
+    /// The determinant of `A`.
+    fn det(&self) -> Self::Output;
+    /// Solve `AX = B` for the `N`-by-`P` matrix `X`, one column at a time.
+    /// If `A` is singular, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 1.0]]);
+    /// let b = Matrix::<2,2,f64>::new([[3.0, 1.0], [2.0, 0.0]]);
+    ///
+    /// let x = Lu::new(&a).solve(&b).unwrap();
+    ///
+    /// assert_eq!(x, Matrix::<2,2,f64>::new([[1.0, 1.0], [1.0, -1.0]]));
+    /// ```
+    fn solve<const P: usize>(
+        &self,
+        b: &Matrix<N, P, Self::Output>,
+    ) -> Option<Matrix<N, P, Self::Output>> {
+        let mut x = *b;
+        for j in 0..P {
+            let mut column = Matrix::<N, 1, Self::Output>::zero();
+            for i in 0..N {
+                column.data[i][0] = b.data[i][j];
+            }
+            let solution = self.solve_column(&column)?;
+            for i in 0..N {
+                x.data[i][j] = solution.data[i][0];
+            }
+        }
+        Some(x)
+    }
+    /// Lazily solve `Ax = b` for each column `b` of `rhs`, reusing this factorization for every solve.
+    /// Solutions for which `A` is singular are [`None`].
+    ///
+    /// Each solve works in fixed-size stack arrays, so streaming any number of right hand sides allocates nothing,
+    /// and there is no scratch buffer to carry from one solve to the next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 1.0]]);
+    /// let lu = Lu::new(&a);
+    ///
+    /// let rhs = (0..1000).map(|k| Matrix::<2,1,f64>::new([[3.0 * k as f64], [2.0 * k as f64]]));
+    /// let solutions: Option<Vec<_>> = lu.solve_many(rhs).collect();
+    ///
+    /// let solutions = solutions.unwrap();
+    /// assert_eq!(solutions.len(), 1000);
+    /// assert_eq!(solutions[7], Matrix::new([[7.0], [7.0]]));
+    /// ```
+    fn solve_many<I>(&self, rhs: I) -> impl Iterator<Item = Option<Matrix<N, 1, Self::Output>>>
+    where
+        I: IntoIterator<Item = Matrix<N, 1, Self::Output>>,
+    {
+        rhs.into_iter().map(move |b| self.solve_column(&b))
+    }
+    /// The inverse of `A`, found by solving `AX = I`.
+    /// If `A` is singular, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 1.0]]);
+    ///
+    /// let a_inv = Lu::new(&a).inverse().unwrap();
+    ///
+    /// assert_eq!(a_inv, SquareMatrix::<2,f64>::new([[1.0, -1.0], [-1.0, 2.0]]));
+    /// ```
+    fn inverse(&self) -> Option<SquareMatrix<N, Self::Output>> {
+        self.solve(&SquareMatrix::<N, Self::Output>::one())
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// Solve `LX = B` for lower triangular `L = self` by forward substitution in `O(N²)` operations per column of `B`,
+    /// reading only the lower triangle of `self`. If a diagonal entry is zero, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let l = SquareMatrix::<3,f64>::new([[2.0, 0.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 2.0, 4.0]]);
+    /// let b = Matrix::<3,2,f64>::new([[2.0, 4.0], [3.0, 2.0], [11.0, 2.0]]);
+    /// assert_eq!(l.solve_lower_triangular(&b), Some(Matrix::new([[1.0, 2.0], [2.0, 0.0], [2.0, 1.0]])));
+    /// ```
+    pub fn solve_lower_triangular<const P: usize>(
+        &self,
+        b: &Matrix<N, P, T>,
+    ) -> Option<Matrix<N, P, T>> {
+        forward_substitution(self, b, false)
+    }
+
+    /// Solve `UX = B` for upper triangular `U = self` by back substitution in `O(N²)` operations per column of `B`,
+    /// reading only the upper triangle of `self`. If a diagonal entry is zero, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let u = SquareMatrix::<3,f64>::new([[1.0, 2.0, -1.0], [0.0, 2.0, 1.0], [0.0, 0.0, 4.0]]);
+    /// let b = Matrix::<3,1,f64>::new([[5.0], [5.0], [4.0]]);
+    /// assert_eq!(u.solve_upper_triangular(&b), Some(Matrix::new([[2.0], [2.0], [1.0]])));
+    ///
+    /// let singular = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 0.0]]);
+    /// assert_eq!(singular.solve_upper_triangular(&Matrix::<2,1,f64>::new([[1.0], [0.0]])), None);
+    /// ```
+    pub fn solve_upper_triangular<const P: usize>(
+        &self,
+        b: &Matrix<N, P, T>,
+    ) -> Option<Matrix<N, P, T>> {
+        back_substitution(self, b)
+    }
+}
+
+/// Solve `lx = b` for lower triangular `l`, treating the diagonal of `l` as ones if `unit_diagonal` is set.
+/// If a diagonal entry is zero, get [`None`] instead.
+pub(crate) fn forward_substitution<const N: usize, const P: usize, T: MatrixEntry + Float>(
+    l: &SquareMatrix<N, T>,
+    b: &Matrix<N, P, T>,
+    unit_diagonal: bool,
+) -> Option<Matrix<N, P, T>> {
+    let mut x = *b;
+    for i in 0..N {
+        let (solved, rest) = x.data.split_at_mut(i);
+        for (j, solved_row) in solved.iter().enumerate() {
+            for (entry, solved_entry) in rest[0].iter_mut().zip(solved_row) {
+                *entry = *entry - l.data[i][j] * *solved_entry;
+            }
+        }
+        if !unit_diagonal {
+            if l.data[i][i].is_zero() {
+                return None;
+            }
+            for entry in rest[0].iter_mut() {
+                *entry = *entry / l.data[i][i];
+            }
+        }
+    }
+    Some(x)
+}
+
+/// Solve `ux = b` for upper triangular `u`.
+/// If a diagonal entry is zero, get [`None`] instead.
+pub(crate) fn back_substitution<const N: usize, const P: usize, T: MatrixEntry + Float>(
+    u: &SquareMatrix<N, T>,
+    b: &Matrix<N, P, T>,
+) -> Option<Matrix<N, P, T>> {
+    let mut x = *b;
+    for i in (0..N).rev() {
+        if u.data[i][i].is_zero() {
+            return None;
+        }
+        let (rest, solved) = x.data.split_at_mut(i + 1);
+        for (j, solved_row) in solved.iter().enumerate() {
+            for (entry, solved_entry) in rest[i].iter_mut().zip(solved_row) {
+                *entry = *entry - u.data[i][i + 1 + j] * *solved_entry;
+            }
+        }
+        for entry in rest[i].iter_mut() {
+            *entry = *entry / u.data[i][i];
+        }
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<M, N, f64>, b: &Matrix<M, N, f64>) {
+        for (a_row, b_row) in a.as_slice().iter().zip(b.as_slice()) {
+            for (x, y) in a_row.iter().zip(b_row) {
+                assert!((x - y).abs() < 1e-10, "{a:?} != {b:?}");
+            }
+        }
+    }
+
+    /// Solve a system and compute a determinant through any factorization
+    fn check_factorization<F: Factorization<3, Output = f64>>(
+        factorization: &F,
+        a: &SquareMatrix<3, f64>,
+        det: f64,
+    ) {
+        let x = Matrix::<3, 1, f64>::new([[1.0], [-2.0], [3.0]]);
+        let b = *a * x;
+        assert_close(&factorization.solve(&b).expect("Singular"), &x);
+        assert!((factorization.det() - det).abs() < 1e-10);
+    }
+
+    /// Check LU, QR and Cholesky agree on a symmetric positive definite [`Matrix`]
+    #[test]
+    fn check_factorizations_agree() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::new([[4.0, 2.0, 2.0], [2.0, 5.0, 3.0], [2.0, 3.0, 6.0]]);
+        check_factorization(&Lu::new(&a), &a, 64.0);
+        check_factorization(&Qr::new(&a), &a, 64.0);
+        check_factorization(&Cholesky::new(&a).ok_or("Not positive definite")?, &a, 64.0);
+        Ok(())
+    }
+
+    /// Check the factors multiply back to the original [`Matrix`]
+    #[test]
+    fn check_factors_reconstruct_matrix() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::new([[0.0, 2.0, 1.0], [1.0, -1.0, 4.0], [3.0, 5.0, -2.0]]);
+        let lu = Lu::new(&a);
+        assert_close(&(lu.get_p() * a), &(lu.get_l() * lu.get_u()));
+
+        let rectangular =
+            Matrix::<4, 2, f64>::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+        let qr = Qr::new(&rectangular);
+        assert_close(&rectangular, &(*qr.get_q() * *qr.get_r()));
+        assert_close(
+            &(qr.get_q().transpose() * *qr.get_q()),
+            &SquareMatrix::<4, f64>::one(),
+        );
+
+        let spd = SquareMatrix::<3, f64>::new([[4.0, 2.0, 2.0], [2.0, 5.0, 3.0], [2.0, 3.0, 6.0]]);
+        let l = *Cholesky::new(&spd).ok_or("Not positive definite")?.get_l();
+        assert_close(&spd, &(l * l.transpose()));
+        Ok(())
+    }
+
+    /// Check a singular [`Matrix`] has zero determinant and cannot be solved
+    #[test]
+    fn check_singular_matrix() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        let lu = Lu::new(&a);
+        assert!(lu.det().abs() < 1e-12);
+        assert_eq!(lu.inverse(), None);
+        assert!(Cholesky::new(&a).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+//! Compile time checks of dimension arithmetic for const generic matrix sizes.
+//!
+//! Stable Rust cannot compute a const generic argument from other const generic parameters, so a method cannot
+//! return a `Matrix<{M + P}, N, T>`. Instead, methods whose output size depends on their input sizes take the output
+//! size as an extra const parameter, usually inferred from how the result is used, and check it against the input
+//! sizes in an inline const block calling one of the checks here, with a message saying what the sizes mean to the
+//! method. A mismatch then fails to compile with that message, rather than panicking at runtime.
+//!
+//! # Examples
+//!
+//! Stack two matrices on top of each other, with the number of rows `Q` of the result checked to be `M + P`,
+//!
+//! ```
+//! # use malg::{dim, Matrix, MatrixEntry};
+//! fn stack<const M: usize, const P: usize, const Q: usize, const N: usize, T: MatrixEntry>(
+//!     top: &Matrix<M, N, T>,
+//!     bottom: &Matrix<P, N, T>,
+//! ) -> Matrix<Q, N, T> {
+//!     const { dim::assert_sum(Q, M, P, "stacked rows must add up") };
+//!     let mut rows = [[T::default(); N]; Q];
+//!     rows[..M].copy_from_slice(top.as_slice());
+//!     rows[M..].copy_from_slice(bottom.as_slice());
+//!     Matrix::new(rows)
+//! }
+//!
+//! let stacked: Matrix<3, 2, u8> = stack(&Matrix::new([[1, 2]]), &Matrix::new([[3, 4], [5, 6]]));
+//! assert_eq!(stacked, Matrix::new([[1, 2], [3, 4], [5, 6]]));
+//! ```
+//!
+//! so that asking for the wrong number of rows fails to compile.
+//!
+//! ```compile_fail
+//! # use malg::{dim, Matrix, MatrixEntry};
+//! # fn stack<const M: usize, const P: usize, const Q: usize, const N: usize, T: MatrixEntry>(
+//! #     top: &Matrix<M, N, T>,
+//! #     bottom: &Matrix<P, N, T>,
+//! # ) -> Matrix<Q, N, T> {
+//! #     const { dim::assert_sum(Q, M, P, "stacked rows must add up") };
+//! #     let mut rows = [[T::default(); N]; Q];
+//! #     rows[..M].copy_from_slice(top.as_slice());
+//! #     rows[M..].copy_from_slice(bottom.as_slice());
+//! #     Matrix::new(rows)
+//! # }
+//! let stacked: Matrix<4, 2, u8> = stack(&Matrix::new([[1, 2]]), &Matrix::new([[3, 4], [5, 6]]));
+//! ```
+//!
+//! # Nightly
+//!
+//! Building on a nightly toolchain with `RUSTFLAGS="--cfg malg_generic_const_exprs"` enables the incomplete
+//! `generic_const_exprs` language feature, and with it `Assert` and `IsTrue`, which state the same checks
+//! as where clauses on a signature, such as `where Assert<{ Q == M + P }>: IsTrue`.
+//! This is a compiler flag rather than a Cargo feature, so `--all-features` builds keep working on stable.
+//! That feature rejects inline const blocks doing anything more than calling a const function, so each check
+//! is a single call in its own const block.
+
+/// Check at compile time that `total` is `a + b`, such as the size of a concatenation of blocks of sizes `a` and `b`.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `total` is not `a + b`.
+pub const fn assert_sum(total: usize, a: usize, b: usize, message: &str) {
+    assert!(a.checked_add(b).is_some() && a + b == total, "{}", message);
+}
+
+/// Check at compile time that `total` is `a * b`, such as the size of a Kronecker product of factors of sizes `a` and `b`.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `total` is not `a * b`.
+pub const fn assert_product(total: usize, a: usize, b: usize, message: &str) {
+    assert!(a.checked_mul(b).is_some() && a * b == total, "{}", message);
+}
+
+/// Check at compile time that the `len` consecutive indices starting at `offset` lie within `0..size`,
+/// such as the rows or columns of a block.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `offset + len` exceeds `size`.
+pub const fn assert_within(offset: usize, len: usize, size: usize, message: &str) {
+    assert!(
+        offset.checked_add(len).is_some() && offset + len <= size,
+        "{}",
+        message
+    );
+}
+
+/// Check at compile time that `size` is at least `min`, such as a matrix needing at least as many rows as columns.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `size` is less than `min`.
+pub const fn assert_at_least(size: usize, min: usize, message: &str) {
+    assert!(size >= min, "{}", message);
+}
+
+/// Check at compile time that `size` is `base` raised to the power `exponent`, such as the size of a matrix built by
+/// repeated doubling.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `size` is not `base.pow(exponent)`.
+pub const fn assert_power(size: usize, base: usize, exponent: u32, message: &str) {
+    assert!(
+        matches!(base.checked_pow(exponent), Some(power) if power == size),
+        "{}",
+        message
+    );
+}
+
+/// Check at compile time that `size` is a power of two, such as the length of a vector split in half repeatedly.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `size` is not a power of two.
+pub const fn assert_power_of_two(size: usize, message: &str) {
+    assert!(size.is_power_of_two(), "{}", message);
+}
+
+/// Check at compile time that `minor` is one less than `size`, such as the size of a matrix with one row or column deleted.
+///
+/// # Panics
+///
+/// Panics with `message`, failing to compile when called in a const block, if `minor + 1` is not `size`.
+pub const fn assert_minor(minor: usize, size: usize, message: &str) {
+    assert!(size > 0 && minor == size - 1, "{}", message);
+}
+
+/// A boolean known at compile time, which implements [`IsTrue`] only when `CHECK` is `true`.
+#[cfg(malg_generic_const_exprs)]
+pub struct Assert<const CHECK: bool>;
+
+/// Implemented by [`Assert`] when its condition holds, for stating dimension checks as where clauses.
+#[cfg(malg_generic_const_exprs)]
+pub trait IsTrue {}
+
+#[cfg(malg_generic_const_exprs)]
+impl IsTrue for Assert<true> {}
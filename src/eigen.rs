@@ -0,0 +1,547 @@
+use num_traits::Float;
+
+use crate::{Factorization, Lu, Matrix, MatrixEntry, MatrixError, SquareMatrix};
+
+/// Maximum number of QR iterations spent on each eigenvalue before giving up.
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/// The `N` eigenvalues of a real `N`-by-`N` matrix, counted with multiplicity.
+///
+/// Complex eigenvalues of a real matrix come in conjugate pairs `a ± bi`, which are stored in adjacent positions
+/// with the positive imaginary part first. Real eigenvalues have an imaginary part of exactly zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Eigenvalues<const N: usize, T: MatrixEntry> {
+    real: [T; N],
+    imaginary: [T; N],
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Eigenvalues<N, T> {
+    /// The real parts of the eigenvalues.
+    pub fn get_real(&self) -> &[T; N] {
+        &self.real
+    }
+
+    /// The imaginary parts of the eigenvalues.
+    pub fn get_imaginary(&self) -> &[T; N] {
+        &self.imaginary
+    }
+
+    /// Whether every eigenvalue is real.
+    pub fn is_real(&self) -> bool {
+        self.imaginary.iter().all(|im| im.is_zero())
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The eigenvalues of a real matrix, in no particular order.
+    ///
+    /// The matrix is [balanced](SquareMatrix::balance) and reduced to its [real Schur form](SquareMatrix::schur).
+    /// Each diagonal entry of the quasi-triangular factor gives a real eigenvalue, and each 2×2 diagonal block
+    /// a conjugate pair of eigenvalues.
+    ///
+    /// If an eigenvalue fails to split off within 30 iterations, get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// The eigenvalues of a companion matrix are the roots of its polynomial, here `(x² + 1)(x - 2)(x + 3)`.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<4,f64>::from_companion(&[1.0, -5.0, 1.0, -6.0]);
+    ///
+    /// let eigenvalues = a.eigenvalues().unwrap();
+    ///
+    /// assert!(!eigenvalues.is_real());
+    /// let mut roots: Vec<(f64, f64)> = eigenvalues
+    ///     .get_real()
+    ///     .iter()
+    ///     .copied()
+    ///     .zip(eigenvalues.get_imaginary().iter().copied())
+    ///     .collect();
+    /// roots.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    /// for (root, expected) in roots.iter().zip([(-3.0, 0.0), (0.0, -1.0), (0.0, 1.0), (2.0, 0.0)]) {
+    ///     assert!((root.0 - expected.0).abs() < 1e-12 && (root.1 - expected.1).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Eigenvalues<N, T>, MatrixError> {
+        let (balanced, _) = self.balance();
+        let t = balanced.schur()?.0.data;
+        let mut real = [T::zero(); N];
+        let mut imaginary = [T::zero(); N];
+        let two = T::one() + T::one();
+        let mut i = 0;
+        while i < N {
+            if i + 1 == N || t[i + 1][i].is_zero() {
+                real[i] = t[i][i];
+                i += 1;
+                continue;
+            }
+            // The eigenvalues `λ = d + p ± √(p² + bc)` of the 2-by-2 block `[a b; c d]`, where `p = (a - d)/2`.
+            let (a, b, c, d) = (t[i][i], t[i][i + 1], t[i + 1][i], t[i + 1][i + 1]);
+            let p = (a - d) / two;
+            let discriminant = p * p + b * c;
+            let root = discriminant.abs().sqrt();
+            if discriminant >= T::zero() {
+                let z = if p >= T::zero() { p + root } else { p - root };
+                real[i] = d + z;
+                real[i + 1] = if z.is_zero() { d } else { d - b * c / z };
+            } else {
+                real[i] = d + p;
+                real[i + 1] = d + p;
+                imaginary[i] = root;
+                imaginary[i + 1] = -root;
+            }
+            i += 2;
+        }
+        Ok(Eigenvalues { real, imaginary })
+    }
+
+    /// The eigenvalue of `self` closest to `shift`, or of smallest magnitude if `shift` is [`None`],
+    /// and a corresponding unit eigenvector, found by inverse iteration.
+    ///
+    /// Inverse iteration is power iteration on `(A - σI)⁻¹`, whose dominant eigenvalue `1/(λ - σ)` belongs to the
+    /// eigenvalue `λ` of `A` closest to the shift `σ`. `A - σI` is factorized once, and each iteration is a pair of
+    /// triangular solves reusing the [`Lu`] factors. The eigenvalue is estimated by the Rayleigh quotient `xᵀAx`, and
+    /// iteration stops once the residual `‖Ax - λx‖` is at most `tol` times the Frobenius norm of `A`.
+    ///
+    /// The eigenvalue closest to the shift must be real and unique in distance from the shift. If the residual is still
+    /// too large after 1000 iterations, get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    ///
+    /// let (smallest, x) = a.smallest_eigenpair(None, 1e-12).unwrap();
+    /// assert!((smallest - (2.0 - 2f64.sqrt())).abs() < 1e-12);
+    /// assert!((a * x - x * smallest).frobenius_norm() < 1e-10);
+    ///
+    /// let (middle, _) = a.smallest_eigenpair(Some(1.9), 1e-12).unwrap();
+    /// assert!((middle - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn smallest_eigenpair(
+        &self,
+        shift: Option<T>,
+        tol: T,
+    ) -> Result<(T, Matrix<N, 1, T>), MatrixError> {
+        const MAX_ITERATIONS: usize = 1000;
+        let norm = self.frobenius_norm();
+        let mut shift = shift.unwrap_or_else(T::zero);
+        let mut lu = Lu::new(&self.shifted(shift));
+        if lu.det().is_zero() {
+            // The shift is an eigenvalue, so nudge it off to keep `A - σI` invertible.
+            shift = shift + T::epsilon().sqrt() * (norm + shift.abs()).max(T::one());
+            lu = Lu::new(&self.shifted(shift));
+        }
+        // Unequal starting entries make a start orthogonal to the wanted eigenvector unlikely.
+        let mut x = Matrix::<N, 1, T>::new(std::array::from_fn(|i| {
+            [T::one() + T::from(i).unwrap_or_else(T::zero) / T::from(N).unwrap_or_else(T::one)]
+        }));
+        x = x * x.frobenius_norm().recip();
+        for _ in 0..MAX_ITERATIONS {
+            let y = lu.solve_column(&x).ok_or(MatrixError::Singular)?;
+            x = y * y.frobenius_norm().recip();
+            let ax = *self * x;
+            let eigenvalue = (x.transpose() * ax).data[0][0];
+            if (ax - x * eigenvalue).frobenius_norm() <= tol * norm {
+                return Ok((eigenvalue, x));
+            }
+        }
+        Err(MatrixError::NotConverged)
+    }
+
+    /// `self - σI` for the shift `σ`.
+    fn shifted(&self, shift: T) -> SquareMatrix<N, T> {
+        let mut shifted = *self;
+        for (i, row) in shifted.data.iter_mut().enumerate() {
+            row[i] = row[i] - shift;
+        }
+        shifted
+    }
+
+    /// The upper Hessenberg form `H = QᵀAQ` of `self`, with zeros below the subdiagonal, and the orthogonal
+    /// similarity transform `Q`, so that `A = QHQᵀ`.
+    ///
+    /// `Q` is the product of Householder reflections zeroing each column below the subdiagonal in turn.
+    /// Similar matrices share eigenvalues, and QR iteration preserves Hessenberg form at a fraction of the cost
+    /// of a full matrix, which makes this the first step of eigenvalue algorithms such as [`SquareMatrix::eigenvalues`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<4,f64>::new([
+    ///     [4.0, 1.0, -2.0, 2.0],
+    ///     [1.0, 2.0, 0.0, 1.0],
+    ///     [-2.0, 0.0, 3.0, -2.0],
+    ///     [2.0, 1.0, -2.0, -1.0],
+    /// ]);
+    ///
+    /// let (h, q) = a.hessenberg();
+    ///
+    /// for (i, row) in h.as_slice().iter().enumerate() {
+    ///     assert!(row.iter().take(i.saturating_sub(1)).all(|entry| *entry == 0.0));
+    /// }
+    /// assert!((q.transpose() * q - SquareMatrix::one()).frobenius_norm() < 1e-12);
+    /// assert!((q * h * q.transpose() - a).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn hessenberg(&self) -> (SquareMatrix<N, T>, SquareMatrix<N, T>) {
+        let mut h = *self;
+        let mut q = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+        for (i, row) in q.data.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+        for k in 0..N.saturating_sub(2) {
+            let norm = (k + 1..N)
+                .fold(T::zero(), |sum, i| sum + h.data[i][k] * h.data[i][k])
+                .sqrt();
+            if norm.is_zero() {
+                continue;
+            }
+            let alpha = if h.data[k + 1][k] > T::zero() {
+                -norm
+            } else {
+                norm
+            };
+            // The Householder vector, zero above row `k + 1`.
+            let mut v = [T::zero(); N];
+            for (v_i, row) in v.iter_mut().zip(h.data.iter()).skip(k + 1) {
+                *v_i = row[k];
+            }
+            v[k + 1] = v[k + 1] - alpha;
+            let v_norm_squared = v.iter().fold(T::zero(), |sum, v_i| sum + *v_i * *v_i);
+            if v_norm_squared.is_zero() {
+                continue;
+            }
+            let factor = (T::one() + T::one()) / v_norm_squared;
+            for j in 0..N {
+                let dot = h
+                    .data
+                    .iter()
+                    .zip(v)
+                    .fold(T::zero(), |sum, (row, v_i)| sum + v_i * row[j]);
+                for (row, v_i) in h.data.iter_mut().zip(v) {
+                    row[j] = row[j] - factor * dot * v_i;
+                }
+            }
+            for row in h.data.iter_mut().chain(q.data.iter_mut()) {
+                let dot = row
+                    .iter()
+                    .zip(v)
+                    .fold(T::zero(), |sum, (entry, v_j)| sum + *entry * v_j);
+                for (entry, v_j) in row.iter_mut().zip(v) {
+                    *entry = *entry - factor * dot * v_j;
+                }
+            }
+            for i in k + 2..N {
+                h.data[i][k] = T::zero();
+            }
+        }
+        (h, q)
+    }
+
+    /// The real Schur form `T = QᵀAQ` of `self` and the orthogonal similarity transform `Q`, so that `A = QTQᵀ`.
+    ///
+    /// `T` is quasi-upper-triangular: upper triangular apart from 2-by-2 blocks on the diagonal, one for each
+    /// conjugate pair of complex eigenvalues. The other eigenvalues appear on the diagonal of `T`, and the leading
+    /// columns of `Q` span the invariant subspaces of the leading eigenvalues, which makes this the starting point
+    /// for matrix functions.
+    ///
+    /// `T` is found by Francis double shift QR iteration on the [Hessenberg form](SquareMatrix::hessenberg) of `self`,
+    /// with 2-by-2 blocks of real eigenvalues split by a final reflection.
+    /// If an eigenvalue has not converged after 30 iterations, get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[1.0, 2.0, 3.0], [-2.0, 1.0, 4.0], [0.5, 1.0, 5.0]]);
+    ///
+    /// let (t, q) = a.schur().unwrap();
+    ///
+    /// assert!((q.transpose() * q - SquareMatrix::one()).frobenius_norm() < 1e-12);
+    /// assert!((q * t * q.transpose() - a).frobenius_norm() < 1e-12);
+    ///
+    /// // A conjugate pair of eigenvalues in a leading 2-by-2 block, and a real eigenvalue below it.
+    /// let t = t.as_slice();
+    /// assert!(t[1][0] != 0.0 && t[2][0] == 0.0 && t[2][1] == 0.0);
+    /// ```
+    ///
+    /// A matrix with real eigenvalues has a triangular Schur form, with its eigenvalues on the diagonal.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 2.0]]);
+    /// let (t, _) = a.schur().unwrap();
+    /// let t = t.as_slice();
+    /// assert_eq!(t[1][0], 0.0);
+    /// assert!((t[0][0] - 3.0).abs() < 1e-12 && (t[1][1] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn schur(&self) -> Result<(SquareMatrix<N, T>, SquareMatrix<N, T>), MatrixError> {
+        let (h, q) = self.hessenberg();
+        let (mut h, mut q) = (h.data, q.data);
+        let eps = T::epsilon();
+        let norm = self.frobenius_norm();
+        let mut hi = N.saturating_sub(1);
+        let mut iterations = 0;
+        while hi > 0 {
+            // Find the top `l` of the unreduced block ending at `hi`, zeroing a negligible subdiagonal entry.
+            let mut l = hi;
+            while l > 0 {
+                let s = h[l - 1][l - 1].abs() + h[l][l].abs();
+                let s = if s.is_zero() { norm } else { s };
+                if h[l][l - 1].abs() <= eps * s {
+                    h[l][l - 1] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+            if l == hi {
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+            let p = hi - 1;
+            if l == p {
+                // Split a 2-by-2 block with real eigenvalues, reflecting an eigenvector onto the first axis.
+                let half_difference = (h[p][p] - h[hi][hi]) / (T::one() + T::one());
+                let discriminant = half_difference * half_difference + h[p][hi] * h[hi][p];
+                if discriminant >= T::zero() {
+                    let root = discriminant.sqrt();
+                    let offset = if half_difference >= T::zero() {
+                        half_difference + root
+                    } else {
+                        half_difference - root
+                    };
+                    let eigenvalue = h[hi][hi] + offset;
+                    let candidates = [
+                        [eigenvalue - h[hi][hi], h[hi][p]],
+                        [h[p][hi], eigenvalue - h[p][p]],
+                    ];
+                    let [first, second] = candidates.map(|[x, y]| x * x + y * y);
+                    let [x, y] = if first >= second {
+                        candidates[0]
+                    } else {
+                        candidates[1]
+                    };
+                    if let Some(reflector) = Reflector::new([x, y, T::zero()], 2) {
+                        reflector.apply(&mut h, &mut q, p, p, hi + 1);
+                    }
+                    h[hi][p] = T::zero();
+                }
+                hi = l.saturating_sub(1);
+                iterations = 0;
+                continue;
+            }
+            if iterations == MAX_ITERATIONS_PER_EIGENVALUE {
+                return Err(MatrixError::NotConverged);
+            }
+            iterations += 1;
+            // The sum and product of the shifts, the eigenvalues of the trailing 2-by-2 block, or exceptional shifts
+            // breaking cycles that the standard shifts can fall into.
+            let (sum, product) = if iterations % 10 == 0 {
+                let s = h[hi][p].abs() + h[p][p - 1].abs();
+                let diagonal = T::from(0.75).unwrap_or_else(T::one) * s + h[hi][hi];
+                let off_diagonal = T::from(0.4375).unwrap_or_else(T::one) * s * s;
+                (diagonal + diagonal, diagonal * diagonal + off_diagonal)
+            } else {
+                (
+                    h[p][p] + h[hi][hi],
+                    h[p][p] * h[hi][hi] - h[p][hi] * h[hi][p],
+                )
+            };
+            // The first column of `(H - σ₁I)(H - σ₂I)`, which determines the first reflection of the step.
+            let mut x = h[l][l] * h[l][l] + h[l][l + 1] * h[l + 1][l] - sum * h[l][l] + product;
+            let mut y = h[l + 1][l] * (h[l][l] + h[l + 1][l + 1] - sum);
+            let mut z = h[l + 1][l] * h[l + 2][l + 1];
+            // Chase the bulge down the block with 3-by-3 reflections, and a final 2-by-2 reflection.
+            for k in l..hi {
+                let len = if k + 1 == hi { 2 } else { 3 };
+                if let Some(reflector) = Reflector::new([x, y, z], len) {
+                    reflector.apply(&mut h, &mut q, k, k.max(l + 1) - 1, hi.min(k + 3) + 1);
+                }
+                if k + 1 < hi {
+                    x = h[k + 1][k];
+                    y = h[k + 2][k];
+                    z = if k + 3 <= hi { h[k + 3][k] } else { T::zero() };
+                }
+            }
+            for (i, row) in h.iter_mut().enumerate().skip(l + 2).take(hi - l - 1) {
+                for entry in row.iter_mut().take(i - 1).skip(l) {
+                    *entry = T::zero();
+                }
+            }
+        }
+        Ok((SquareMatrix::new(h), SquareMatrix::new(q)))
+    }
+}
+
+/// A Householder reflection `I - βuuᵀ` acting on `len` consecutive coordinates, mapping the vector it was constructed
+/// from onto the first of them.
+struct Reflector<T> {
+    u: [T; 3],
+    len: usize,
+    beta: T,
+}
+
+impl<T: Float> Reflector<T> {
+    /// The reflection of the first `len` entries of `v`, or [`None`] if they are zero.
+    fn new(v: [T; 3], len: usize) -> Option<Self> {
+        let norm = v
+            .iter()
+            .take(len)
+            .fold(T::zero(), |sum, v_i| sum + *v_i * *v_i)
+            .sqrt();
+        if norm.is_zero() {
+            return None;
+        }
+        let mut u = v;
+        u[0] = if u[0] >= T::zero() {
+            u[0] + norm
+        } else {
+            u[0] - norm
+        };
+        let u_norm_squared = u
+            .iter()
+            .take(len)
+            .fold(T::zero(), |sum, u_i| sum + *u_i * *u_i);
+        Some(Reflector {
+            u,
+            len,
+            beta: (T::one() + T::one()) / u_norm_squared,
+        })
+    }
+
+    /// Apply the reflection as a similarity transform to rows and columns `k..k + len` of `h`, skipping the columns
+    /// before `first_col` and the rows from `end_row` on, where the entries are zero, and accumulate it into `q`.
+    fn apply<const N: usize>(
+        &self,
+        h: &mut [[T; N]; N],
+        q: &mut [[T; N]; N],
+        k: usize,
+        first_col: usize,
+        end_row: usize,
+    ) {
+        let rows = &mut h[k..k + self.len];
+        for j in first_col..N {
+            let dot = rows
+                .iter()
+                .zip(self.u)
+                .fold(T::zero(), |sum, (row, u_i)| sum + u_i * row[j]);
+            for (row, u_i) in rows.iter_mut().zip(self.u) {
+                row[j] = row[j] - self.beta * dot * u_i;
+            }
+        }
+        for row in h.iter_mut().take(end_row).chain(q.iter_mut()) {
+            let entries = &mut row[k..k + self.len];
+            let dot = entries
+                .iter()
+                .zip(self.u)
+                .fold(T::zero(), |sum, (entry, u_j)| sum + *entry * u_j);
+            for (entry, u_j) in entries.iter_mut().zip(self.u) {
+                *entry = *entry - self.beta * dot * u_j;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check the eigenvalues of a symmetric [`Matrix`] are real, and sum and multiply to its trace and determinant
+    #[test]
+    fn check_symmetric_eigenvalues() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<4, f64>::new([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+        let eigenvalues = a.eigenvalues()?;
+        assert!(eigenvalues.is_real());
+        let sum: f64 = eigenvalues.get_real().iter().sum();
+        let product: f64 = eigenvalues.get_real().iter().product();
+        assert!((sum - a.trace()).abs() < 1e-10);
+        assert!((product - a.determinant()).abs() < 1e-10);
+        Ok(())
+    }
+
+    /// Check the eigenvalues of a triangular [`Matrix`] are its diagonal, and of the zero [`Matrix`] are zero
+    #[test]
+    fn check_trivial_eigenvalues() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::new([[1.0, 5.0, -2.0], [0.0, -3.0, 7.0], [0.0, 0.0, 2.0]]);
+        let mut eigenvalues = *a.eigenvalues()?.get_real();
+        eigenvalues.sort_by(f64::total_cmp);
+        assert_eq!(eigenvalues, [-3.0, 1.0, 2.0]);
+
+        let zero = SquareMatrix::<3, f64>::new([[0.0; 3]; 3]).eigenvalues()?;
+        assert_eq!(zero.get_real(), &[0.0; 3]);
+        assert!(zero.is_real());
+        Ok(())
+    }
+
+    /// Check the real Schur form of a [`Matrix`] with real and complex eigenvalues is quasi-upper-triangular,
+    /// similar to the [`Matrix`], and has its eigenvalues
+    #[test]
+    fn check_schur_form() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<5, f64>::new([
+            [1.0, 2.0, 0.0, -1.0, 3.0],
+            [-2.0, 1.0, 4.0, 0.5, 0.0],
+            [0.5, -3.0, 2.0, 1.0, 1.0],
+            [1.0, 0.0, -1.0, 3.0, 2.0],
+            [0.0, 2.0, 1.0, -2.0, 1.0],
+        ]);
+        let (t, q) = a.schur()?;
+        assert!((q.transpose() * q).is_identity_with_tol(1e-12));
+        assert!((q * t * q.transpose()).distance_frobenius(&a) < 1e-11);
+
+        let t = t.as_slice();
+        let mut trace = 0.0;
+        for (i, row) in t.iter().enumerate() {
+            assert!(row
+                .iter()
+                .take(i.saturating_sub(1))
+                .all(|entry| *entry == 0.0));
+            if i > 1 {
+                assert!(t[i][i - 1] == 0.0 || t[i - 1][i - 2] == 0.0);
+            }
+            trace += row[i];
+        }
+        assert!((trace - a.trace()).abs() < 1e-10);
+
+        // Eigenvalues of the diagonal blocks of `t`, matched against the eigenvalues of `a`.
+        let mut blocks = Vec::new();
+        let mut i = 0;
+        while i < 5 {
+            if i + 1 < 5 && t[i + 1][i] != 0.0 {
+                let mean = (t[i][i] + t[i + 1][i + 1]) / 2.0;
+                let half_difference = (t[i][i] - t[i + 1][i + 1]) / 2.0;
+                let discriminant = half_difference * half_difference + t[i][i + 1] * t[i + 1][i];
+                assert!(discriminant < 0.0);
+                blocks.push((mean, (-discriminant).sqrt()));
+                blocks.push((mean, -(-discriminant).sqrt()));
+                i += 2;
+            } else {
+                blocks.push((t[i][i], 0.0));
+                i += 1;
+            }
+        }
+        let eigenvalues = a.eigenvalues()?;
+        for (re, im) in eigenvalues
+            .get_real()
+            .iter()
+            .zip(eigenvalues.get_imaginary())
+        {
+            assert!(blocks
+                .iter()
+                .any(|(block_re, block_im)| (re - block_re).hypot(im - block_im) < 1e-9));
+        }
+        Ok(())
+    }
+}
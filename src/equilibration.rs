@@ -0,0 +1,184 @@
+use num_traits::Float;
+
+use crate::{Factorization, Lu, Matrix, MatrixEntry, SquareMatrix};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, T> {
+    /// Diagonal row and column scaling matrices `(R, C)` equilibrating `self`, so that the largest
+    /// entry in every row and column of `RAC` has magnitude one.
+    ///
+    /// Rows are scaled first by the reciprocal of their largest magnitude, then columns of the row-scaled matrix likewise.
+    /// Rows or columns that are entirely zero are left unscaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1024.0, 2048.0], [4.0, 2.0]]);
+    ///
+    /// let (r, c) = a.equilibrate();
+    ///
+    /// assert_eq!(r, Matrix::new([[1.0 / 2048.0, 0.0], [0.0, 0.25]]));
+    /// assert_eq!(c, Matrix::new([[1.0, 0.0], [0.0, 1.0]]));
+    /// assert_eq!(r * a * c, Matrix::new([[0.5, 1.0], [1.0, 0.5]]));
+    /// ```
+    pub fn equilibrate(&self) -> (SquareMatrix<M, T>, SquareMatrix<N, T>) {
+        let (row_scales, col_scales) = self.equilibration_scales();
+        (diagonal(&row_scales), diagonal(&col_scales))
+    }
+
+    /// The diagonals of the row and column scaling matrices of [`Matrix::equilibrate`].
+    fn equilibration_scales(&self) -> ([T; M], [T; N]) {
+        let reciprocal = |largest: T| {
+            if largest.is_zero() {
+                T::one()
+            } else {
+                T::one() / largest
+            }
+        };
+        let mut row_scales = [T::one(); M];
+        for (scale, row) in row_scales.iter_mut().zip(self.data.iter()) {
+            *scale = reciprocal(
+                row.iter()
+                    .fold(T::zero(), |largest, e| largest.max(e.abs())),
+            );
+        }
+        let mut col_scales = [T::one(); N];
+        for (j, scale) in col_scales.iter_mut().enumerate() {
+            let largest = self
+                .data
+                .iter()
+                .zip(row_scales)
+                .fold(T::zero(), |largest, (row, r)| {
+                    largest.max((row[j] * r).abs())
+                });
+            *scale = reciprocal(largest);
+        }
+        (row_scales, col_scales)
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// Solve `AX = B` after equilibrating `A`, by solving `(RAC)Y = RB` and then undoing the column scaling with `X = CY`.
+    /// This protects the solve from badly scaled rows and columns, such as equations written in very different units.
+    /// If `A` is singular, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1e10, 2e10], [3.0, 4.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[5e10], [11.0]]);
+    ///
+    /// let x = a.solve_equilibrated(&b).unwrap();
+    ///
+    /// assert!((*x.get_entry(0,0).unwrap() - 1.0).abs() < 1e-12);
+    /// assert!((*x.get_entry(1,0).unwrap() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn solve_equilibrated<const P: usize>(
+        &self,
+        b: &Matrix<N, P, T>,
+    ) -> Option<Matrix<N, P, T>> {
+        let (r, c) = self.equilibration_scales();
+        let y = Lu::new(&self.scale_rows_by(&r).scale_cols_by(&c)).solve(&b.scale_rows_by(&r))?;
+        Some(y.scale_rows_by(&c))
+    }
+
+    /// The balanced matrix `D⁻¹AD` and the diagonal similarity scaling `D` making the 1-norms of each row and
+    /// the corresponding column of the balanced matrix nearly equal, as in LAPACK's `gebal` without permutations.
+    ///
+    /// Balancing leaves the eigenvalues unchanged but reduces the norm of the matrix, which improves the accuracy
+    /// of eigenvalue computations on badly scaled matrices. Scaling factors are powers of two, so balancing
+    /// introduces no rounding errors. Rows or columns that are zero off the diagonal, or that have an entry which is
+    /// not finite, are left unscaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 1024.0], [1.0 / 1024.0, 1.0]]);
+    ///
+    /// let (b, d) = a.balance();
+    ///
+    /// assert_eq!(b, SquareMatrix::new([[1.0, 1.0], [1.0, 1.0]]));
+    /// assert_eq!(d * b * d.inverse().unwrap(), a);
+    /// assert!((b.trace() - a.trace()).abs() < 1e-15 && (b.determinant() - a.determinant()).abs() < 1e-15);
+    /// ```
+    pub fn balance(&self) -> (SquareMatrix<N, T>, SquareMatrix<N, T>) {
+        let radix = T::one() + T::one();
+        let radix_squared = radix * radix;
+        let threshold = T::from(0.95).unwrap_or_else(T::one);
+        let mut balanced = *self;
+        let mut scales = [T::one(); N];
+        let mut converged = false;
+        while !converged {
+            converged = true;
+            for i in 0..N {
+                let (mut col_norm, row_norm) = (0..N).filter(|&j| j != i).fold(
+                    (T::zero(), T::zero()),
+                    |(col_norm, row_norm), j| {
+                        (
+                            col_norm + balanced.data[j][i].abs(),
+                            row_norm + balanced.data[i][j].abs(),
+                        )
+                    },
+                );
+                let total = col_norm + row_norm;
+                if col_norm.is_zero() || row_norm.is_zero() || !total.is_finite() {
+                    continue;
+                }
+                let mut f = T::one();
+                while col_norm < row_norm / radix {
+                    f = f * radix;
+                    col_norm = col_norm * radix_squared;
+                }
+                while col_norm >= row_norm * radix {
+                    f = f / radix;
+                    col_norm = col_norm / radix_squared;
+                }
+                if (col_norm + row_norm) / f < threshold * total {
+                    converged = false;
+                    scales[i] = scales[i] * f;
+                    for entry in balanced.data[i].iter_mut() {
+                        *entry = *entry / f;
+                    }
+                    for row in balanced.data.iter_mut() {
+                        row[i] = row[i] * f;
+                    }
+                }
+            }
+        }
+        (balanced, diagonal(&scales))
+    }
+}
+
+/// The diagonal matrix with diagonal `entries`.
+fn diagonal<const N: usize, T: MatrixEntry + Float>(entries: &[T; N]) -> SquareMatrix<N, T> {
+    let mut d = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+    for (i, entry) in entries.iter().enumerate() {
+        d.data[i][i] = *entry;
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check balancing a [`Matrix`] with an infinite off-diagonal entry terminates, leaving its row and column unscaled
+    #[test]
+    fn check_balance_infinite_entry() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::new([
+            [1.0, f64::INFINITY, 0.0],
+            [1.0, 1.0, 1024.0],
+            [0.0, 1.0 / 1024.0, 1.0],
+        ]);
+        let (b, d) = a.balance();
+        assert_eq!(d.as_slice()[0][0], 1.0);
+        assert_eq!(d.as_slice()[1][1], 1.0);
+        assert_eq!(b.as_slice()[0][1], f64::INFINITY);
+        assert_eq!(b.as_slice()[2][2], 1.0);
+        Ok(())
+    }
+}
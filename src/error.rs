@@ -0,0 +1,36 @@
+use std::{error::Error, fmt};
+
+/// Reasons a matrix computation can fail.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum MatrixError {
+    /// A matrix that needed to be inverted, or a system that needed to be solved, is singular.
+    Singular,
+    /// An intermediate or final value does not fit in the type used to compute it.
+    Overflow,
+    /// An iterative method did not converge within its iteration limit.
+    NotConverged,
+    /// A matrix that needed to commute with its conjugate transpose does not.
+    NotNormal,
+    /// An observer cancelled the computation before it finished.
+    Cancelled,
+    /// A system of equations has no solution.
+    Inconsistent,
+    /// A system of equations has infinitely many solutions.
+    Underdetermined,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::Overflow => write!(f, "arithmetic overflow"),
+            MatrixError::NotConverged => write!(f, "iteration did not converge"),
+            MatrixError::NotNormal => write!(f, "matrix is not normal"),
+            MatrixError::Cancelled => write!(f, "computation was cancelled"),
+            MatrixError::Inconsistent => write!(f, "system has no solution"),
+            MatrixError::Underdetermined => write!(f, "system has infinitely many solutions"),
+        }
+    }
+}
+
+impl Error for MatrixError {}
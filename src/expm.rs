@@ -0,0 +1,78 @@
+use num_traits::{Float, One};
+
+use crate::{AbsValue, Factorization, Lu, MatrixEntry, SquareMatrix};
+
+/// Degree of the numerator and denominator of the diagonal Padé approximant used by [`SquareMatrix::expm`].
+const PADE_DEGREE: i32 = 6;
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The matrix exponential `eᴬ = I + A + A²/2! + ⋯` of `self`, the solution operator of the linear system of
+    /// differential equations `x′ = Ax`, so that `x(t) = eᴬᵗx(0)`.
+    ///
+    /// It is found by scaling and squaring: `self` is scaled by `2⁻ˢ` until its infinity-norm is at most one half,
+    /// the exponential of the scaled matrix is approximated by the `[6/6]` Padé approximant `Q(A)⁻¹P(A)`,
+    /// and the result is squared `s` times. If `self` has an entry that is not finite, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// The exponential of a skew-symmetric matrix is a rotation,
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let theta = 1.2f64;
+    /// let generator = SquareMatrix::<2,f64>::new([[0.0, -theta], [theta, 0.0]]);
+    /// let rotation = SquareMatrix::new([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]]);
+    /// assert!((generator.expm().unwrap() - rotation).frobenius_norm() < 1e-14);
+    /// ```
+    ///
+    /// and a continuous-time system `x′ = Ax` sampled every `Δt` becomes the discrete-time system
+    /// `xₖ₊₁ = e^{AΔt}xₖ`, here a decaying mode and a double integrator.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[-2.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    /// let dt = 0.5;
+    ///
+    /// let discrete = (a * dt).expm().unwrap();
+    ///
+    /// let expected = SquareMatrix::new([[(-1.0f64).exp(), 0.0, 0.0], [0.0, 1.0, dt], [0.0, 0.0, 1.0]]);
+    /// assert!((discrete - expected).frobenius_norm() < 1e-14);
+    /// ```
+    pub fn expm(&self) -> Option<Self>
+    where
+        T: AbsValue,
+    {
+        if self.data.iter().flatten().any(|entry| !entry.is_finite()) {
+            return None;
+        }
+        let two = T::one() + T::one();
+        let norm_inf = self.norm_inf();
+        let squarings = if norm_inf > T::zero() {
+            (norm_inf.log2().floor().to_i32()? + 2).max(0)
+        } else {
+            0
+        };
+        let a = *self * two.powi(-squarings);
+        let identity = Self::one();
+        let mut coefficient = T::one() / two;
+        let mut power = a;
+        let mut numerator = identity + a * coefficient;
+        let mut denominator = identity - a * coefficient;
+        for k in 2..=PADE_DEGREE {
+            coefficient = coefficient * T::from(PADE_DEGREE - k + 1)?
+                / T::from(k * (2 * PADE_DEGREE - k + 1))?;
+            power = a * power;
+            numerator = numerator + power * coefficient;
+            if k % 2 == 0 {
+                denominator = denominator + power * coefficient;
+            } else {
+                denominator = denominator - power * coefficient;
+            }
+        }
+        let mut exponential = Lu::new(&denominator).solve(&numerator)?;
+        for _ in 0..squarings {
+            exponential = exponential * exponential;
+        }
+        Some(exponential)
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt::{self, Debug, Display, LowerExp};
+
+use crate::{AugmentedMatrix, Matrix, MatrixEntry};
+
+/// Layout used when printing a matrix.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub enum MatrixStyle {
+    /// One row per line, with entries separated by the formatter's separator and aligned in columns.
+    #[default]
+    Plain,
+    /// A LaTeX `bmatrix` environment.
+    Latex,
+    /// A Markdown table with an empty header row.
+    Markdown,
+}
+
+/// Options controlling how the entries of a matrix are printed, shared by every [`MatrixStyle`].
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{Matrix, MatrixFormatter};
+/// let a = Matrix::<2,2,f64>::new([[1.0, -2.5], [30.0, 4.25]]);
+/// let formatter = MatrixFormatter::new().precision(1).separator(", ");
+/// assert_eq!(a.format_with(&formatter), " 1.0, -2.5\n30.0,  4.2");
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct MatrixFormatter {
+    precision: Option<usize>,
+    width: Option<usize>,
+    scientific: bool,
+    separator: String,
+    style: MatrixStyle,
+    /// Number of leading columns separated from the rest by `|` in the [`MatrixStyle::Plain`] style,
+    /// as in an augmented matrix.
+    divider: Option<usize>,
+}
+
+impl MatrixFormatter {
+    /// A [`MatrixFormatter`] printing entries as they are displayed, in the [`MatrixStyle::Plain`] style with entries separated by a space.
+    pub fn new() -> Self {
+        MatrixFormatter {
+            precision: None,
+            width: None,
+            scientific: false,
+            separator: String::from(" "),
+            style: MatrixStyle::Plain,
+            divider: None,
+        }
+    }
+    /// Print entries with `precision` digits after the decimal point.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+    /// Right-align entries in fields at least `width` characters wide.
+    /// Without a width, plain entries are padded to the widest entry so that columns line up.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+    /// Print entries in scientific notation, such as `1.5e3`.
+    pub fn scientific(mut self, scientific: bool) -> Self {
+        self.scientific = scientific;
+        self
+    }
+    /// Separate entries in a row of a [`MatrixStyle::Plain`] matrix with `separator`.
+    /// The other styles use the separator their syntax requires.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+    /// Lay the matrix out in the style `style`.
+    pub fn style(mut self, style: MatrixStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Lay out the rows of entries `rows`, which have already been converted to text.
+    fn layout(&self, rows: Vec<Vec<String>>) -> String {
+        let width = self.width.unwrap_or_else(|| match self.style {
+            MatrixStyle::Plain => rows
+                .iter()
+                .flatten()
+                .map(|entry| entry.chars().count())
+                .max()
+                .unwrap_or(0),
+            MatrixStyle::Latex | MatrixStyle::Markdown => 0,
+        });
+        let rows: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|entry| format!("{entry:>width$}"))
+                    .collect()
+            })
+            .collect();
+        match self.style {
+            MatrixStyle::Plain => rows
+                .into_iter()
+                .map(|mut row| {
+                    if let Some(divider) = self.divider.filter(|&divider| divider <= row.len()) {
+                        row.insert(divider, String::from("|"));
+                    }
+                    row.join(&self.separator)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            MatrixStyle::Latex => {
+                let body = rows
+                    .iter()
+                    .map(|row| row.join(" & "))
+                    .collect::<Vec<_>>()
+                    .join(" \\\\\n");
+                format!("\\begin{{bmatrix}}\n{body}\n\\end{{bmatrix}}")
+            }
+            MatrixStyle::Markdown => {
+                let columns = rows.first().map_or(0, Vec::len);
+                let header = format!("|{}", "   |".repeat(columns));
+                let rule = format!("|{}", "---|".repeat(columns));
+                let body = rows.iter().map(|row| format!("| {} |", row.join(" | ")));
+                [header, rule]
+                    .into_iter()
+                    .chain(body)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        }
+    }
+}
+
+impl Default for MatrixFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Display + LowerExp> Matrix<M, N, T> {
+    /// The matrix printed according to `formatter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, MatrixFormatter};
+    /// let a = Matrix::<2,2,f64>::new([[1500.0, 2.0], [0.25, 4.0]]);
+    /// let formatter = MatrixFormatter::new().precision(2).scientific(true).width(9);
+    /// assert_eq!(a.format_with(&formatter), "   1.50e3    2.00e0\n  2.50e-1    4.00e0");
+    /// ```
+    pub fn format_with(&self, formatter: &MatrixFormatter) -> String {
+        let rows = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| match (formatter.scientific, formatter.precision) {
+                        (true, Some(precision)) => format!("{entry:.precision$e}"),
+                        (true, None) => format!("{entry:e}"),
+                        (false, Some(precision)) => format!("{entry:.precision$}"),
+                        (false, None) => format!("{entry}"),
+                    })
+                    .collect()
+            })
+            .collect();
+        formatter.layout(rows)
+    }
+
+    /// The matrix as a LaTeX `bmatrix` environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,u8>::new([[1, 2], [3, 4]]);
+    /// assert_eq!(a.to_latex(), "\\begin{bmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{bmatrix}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.format_with(&MatrixFormatter::new().style(MatrixStyle::Latex))
+    }
+
+    /// The matrix as a Markdown table with an empty header row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,u8>::new([[1, 2], [3, 4]]);
+    /// assert_eq!(a.to_markdown(), "|   |   |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |");
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        self.format_with(&MatrixFormatter::new().style(MatrixStyle::Markdown))
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Display> Display for Matrix<M, N, T> {
+    /// Print the matrix in the [`MatrixStyle::Plain`] style, one row per line with columns aligned.
+    /// The precision and width of the format string are applied to every entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, -2.0], [3.5, 10.0]]);
+    /// assert_eq!(format!("{a}"), "  1  -2\n3.5  10");
+    /// assert_eq!(format!("{a:.1}"), " 1.0 -2.0\n 3.5 10.0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut formatter = MatrixFormatter::new();
+        formatter.precision = f.precision();
+        formatter.width = f.width();
+        let rows = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| match formatter.precision {
+                        Some(precision) => format!("{entry:.precision$}"),
+                        None => format!("{entry}"),
+                    })
+                    .collect()
+            })
+            .collect();
+        write!(f, "{}", formatter.layout(rows))
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize, T: MatrixEntry + Display> Display
+    for AugmentedMatrix<M, N, P, T>
+{
+    /// Print the augmented matrix one row per line with columns aligned, separating the blocks with `|`.
+    /// The precision and width of the format string are applied to every entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[5.0], [-6.0]]);
+    /// assert_eq!(format!("{}", a.augment(&b)), " 1  2 |  5\n 3  4 | -6");
+    /// assert_eq!(format!("{:.1}", a.augment(&b)), " 1.0  2.0 |  5.0\n 3.0  4.0 | -6.0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let text = |entry: &T| match precision {
+            Some(precision) => format!("{entry:.precision$}"),
+            None => format!("{entry}"),
+        };
+        write_augmented(f, self, text)
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize, T: MatrixEntry + Debug> Debug
+    for AugmentedMatrix<M, N, P, T>
+{
+    /// Print the augmented matrix like its [`Display`], using the [`Debug`] representation of each entry,
+    /// so that elimination steps can be followed in test failures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,i32>::new([[1, 2], [0, 1]]);
+    /// let b = Matrix::<2,1,i32>::new([[5], [2]]);
+    /// assert_eq!(format!("{:?}", a.augment(&b)), "1 2 | 5\n0 1 | 2");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let text = |entry: &T| match precision {
+            Some(precision) => format!("{entry:.precision$?}"),
+            None => format!("{entry:?}"),
+        };
+        write_augmented(f, self, text)
+    }
+}
+
+/// Write the rows of `augmented` with the entries converted to text by `text`, laid out by a [`MatrixFormatter`]
+/// with the width of the format string and the blocks separated by `|`.
+fn write_augmented<const M: usize, const N: usize, const P: usize, T: MatrixEntry>(
+    f: &mut fmt::Formatter<'_>,
+    augmented: &AugmentedMatrix<M, N, P, T>,
+    text: impl Fn(&T) -> String,
+) -> fmt::Result {
+    let mut formatter = MatrixFormatter::new();
+    formatter.width = f.width();
+    formatter.divider = Some(N);
+    let rows = augmented
+        .get_left()
+        .as_slice()
+        .iter()
+        .zip(augmented.get_right().as_slice())
+        .map(|(left, right)| left.iter().chain(right).map(&text).collect())
+        .collect();
+    write!(f, "{}", formatter.layout(rows))
+}
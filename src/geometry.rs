@@ -0,0 +1,279 @@
+use num_traits::Float;
+
+use crate::{dim, ColumnVector, Factorization, Lu, MatrixEntry, SquareMatrix};
+
+/// Plane in three dimensions with entries of type `T`, the points `x` satisfying `n·x = d`
+/// for a normal vector `n` and offset `d`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Plane<T: MatrixEntry> {
+    normal: ColumnVector<3, T>,
+    offset: T,
+}
+
+impl<T: MatrixEntry + Float> Plane<T> {
+    /// A new [`Plane`] of the points `x` satisfying `normal·x = offset`.
+    pub fn new(normal: ColumnVector<3, T>, offset: T) -> Plane<T> {
+        Plane::<T> { normal, offset }
+    }
+    /// A new [`Plane`] through `point` with normal vector `normal`.
+    pub fn through_point(normal: ColumnVector<3, T>, point: &ColumnVector<3, T>) -> Plane<T> {
+        Plane::new(normal, normal.dot(point))
+    }
+    /// Normal vector of the plane.
+    pub fn get_normal(&self) -> &ColumnVector<3, T> {
+        &self.normal
+    }
+    /// Offset of the plane along its normal vector.
+    pub fn get_offset(&self) -> T {
+        self.offset
+    }
+
+    /// The signed distance from the plane to `point`, positive on the side the normal vector points to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{ColumnVector, Plane};
+    /// let plane = Plane::new(ColumnVector::<3,f64>::new([[0.0], [0.0], [2.0]]), 2.0);
+    /// assert_eq!(plane.distance_to(&ColumnVector::new([[5.0], [-3.0], [4.0]])), 3.0);
+    /// assert_eq!(plane.distance_to(&ColumnVector::new([[5.0], [-3.0], [-1.0]])), -2.0);
+    /// ```
+    pub fn distance_to(&self, point: &ColumnVector<3, T>) -> T {
+        (self.normal.dot(point) - self.offset) / self.normal.dot(&self.normal).sqrt()
+    }
+
+    /// The point where the line through `point` with direction `direction` meets the plane,
+    /// or `None` if the line is parallel to the plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{ColumnVector, Plane};
+    /// let plane = Plane::new(ColumnVector::<3,f64>::new([[0.0], [0.0], [1.0]]), 2.0);
+    /// let point = ColumnVector::new([[1.0], [1.0], [0.0]]);
+    ///
+    /// let hit = plane.intersect_line(&point, &ColumnVector::new([[1.0], [0.0], [1.0]]));
+    /// assert_eq!(hit, Some(ColumnVector::new([[3.0], [1.0], [2.0]])));
+    ///
+    /// let miss = plane.intersect_line(&point, &ColumnVector::new([[1.0], [0.0], [0.0]]));
+    /// assert_eq!(miss, None);
+    /// ```
+    pub fn intersect_line(
+        &self,
+        point: &ColumnVector<3, T>,
+        direction: &ColumnVector<3, T>,
+    ) -> Option<ColumnVector<3, T>> {
+        let rate = self.normal.dot(direction);
+        if rate.is_zero() {
+            return None;
+        }
+        Some(*point + *direction * ((self.offset - self.normal.dot(point)) / rate))
+    }
+
+    /// The single point shared by `self`, `second` and `third`, found by solving the 3×3 system
+    /// of their equations, or `None` if the normal vectors are linearly dependent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{ColumnVector, Plane};
+    /// let x = Plane::new(ColumnVector::<3,f64>::new([[1.0], [0.0], [0.0]]), 1.0);
+    /// let y = Plane::new(ColumnVector::new([[0.0], [1.0], [0.0]]), 2.0);
+    /// let z = Plane::new(ColumnVector::new([[0.0], [0.0], [1.0]]), 3.0);
+    /// assert_eq!(x.intersect_planes(&y, &z), Some(ColumnVector::new([[1.0], [2.0], [3.0]])));
+    ///
+    /// let parallel = Plane::new(ColumnVector::new([[2.0], [0.0], [0.0]]), 5.0);
+    /// assert_eq!(x.intersect_planes(&y, &parallel), None);
+    /// ```
+    pub fn intersect_planes(
+        &self,
+        second: &Plane<T>,
+        third: &Plane<T>,
+    ) -> Option<ColumnVector<3, T>> {
+        let planes = [self, second, third];
+        let normals = SquareMatrix::<3, T>::new(planes.map(|plane| {
+            let n = plane.normal.data;
+            [n[0][0], n[1][0], n[2][0]]
+        }));
+        let offsets = ColumnVector::<3, T>::new(planes.map(|plane| [plane.offset]));
+        Lu::new(&normals).solve_column(&offsets)
+    }
+}
+
+/// The matrix whose columns are the vertices of `triangle` in homogeneous coordinates.
+fn homogeneous_vertices<T: MatrixEntry + Float>(
+    triangle: &[ColumnVector<2, T>; 3],
+) -> SquareMatrix<3, T> {
+    let [a, b, c] = triangle.map(|vertex| vertex.data);
+    SquareMatrix::new([
+        [a[0][0], b[0][0], c[0][0]],
+        [a[1][0], b[1][0], c[1][0]],
+        [T::one(), T::one(), T::one()],
+    ])
+}
+
+/// The barycentric coordinates of `point` with respect to the vertices of `triangle`,
+/// the weights summing to one whose combination of the vertices is `point`,
+/// or `None` if the triangle is degenerate.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{barycentric_coordinates, ColumnVector};
+/// let triangle = [
+///     ColumnVector::<2,f64>::new([[0.0], [0.0]]),
+///     ColumnVector::new([[4.0], [0.0]]),
+///     ColumnVector::new([[0.0], [2.0]]),
+/// ];
+/// let weights = barycentric_coordinates(&ColumnVector::new([[1.0], [1.0]]), &triangle);
+/// assert_eq!(weights, Some(ColumnVector::new([[0.25], [0.25], [0.5]])));
+/// ```
+pub fn barycentric_coordinates<T: MatrixEntry + Float>(
+    point: &ColumnVector<2, T>,
+    triangle: &[ColumnVector<2, T>; 3],
+) -> Option<ColumnVector<3, T>> {
+    let p = point.data;
+    Lu::new(&homogeneous_vertices(triangle)).solve_column(&ColumnVector::new([
+        [p[0][0]],
+        [p[1][0]],
+        [T::one()],
+    ]))
+}
+
+/// The barycentric coordinates of each of `points` with respect to the vertices of `triangle`,
+/// factorizing the triangle once for all points, or `None` if the triangle is degenerate.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{barycentric_coordinates_batch, ColumnVector};
+/// let triangle = [
+///     ColumnVector::<2,f64>::new([[0.0], [0.0]]),
+///     ColumnVector::new([[4.0], [0.0]]),
+///     ColumnVector::new([[0.0], [2.0]]),
+/// ];
+/// let points = [ColumnVector::new([[4.0], [0.0]]), ColumnVector::new([[2.0], [0.0]])];
+/// let weights = barycentric_coordinates_batch(&points, &triangle).unwrap();
+/// assert_eq!(weights, [ColumnVector::new([[0.0], [1.0], [0.0]]), ColumnVector::new([[0.5], [0.5], [0.0]])]);
+///
+/// let collinear = [triangle[0], triangle[1], ColumnVector::new([[2.0], [0.0]])];
+/// assert_eq!(barycentric_coordinates_batch(&points, &collinear), None);
+/// ```
+pub fn barycentric_coordinates_batch<T: MatrixEntry + Float>(
+    points: &[ColumnVector<2, T>],
+    triangle: &[ColumnVector<2, T>; 3],
+) -> Option<Vec<ColumnVector<3, T>>> {
+    let lu = Lu::new(&homogeneous_vertices(triangle));
+    points
+        .iter()
+        .map(|point| {
+            let p = point.data;
+            lu.solve_column(&ColumnVector::new([[p[0][0]], [p[1][0]], [T::one()]]))
+        })
+        .collect()
+}
+
+/// The inertia tensor `Σ mᵢ (‖rᵢ‖² I - rᵢrᵢᵀ)` about the origin of the point masses `masses`,
+/// given as pairs of a mass `mᵢ` and its position `rᵢ`.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{inertia_tensor, SquareMatrix};
+/// let masses = [(2.0, [1.0, 0.0, 0.0]), (2.0, [-1.0, 0.0, 0.0]), (1.0, [0.0, 1.0, 1.0])];
+/// let inertia = inertia_tensor(&masses);
+/// assert_eq!(inertia, SquareMatrix::<3,f64>::new([[2.0, 0.0, 0.0], [0.0, 5.0, -1.0], [0.0, -1.0, 5.0]]));
+/// ```
+pub fn inertia_tensor<T: MatrixEntry + Float>(masses: &[(T, [T; 3])]) -> SquareMatrix<3, T> {
+    let mut inertia = SquareMatrix::<3, T>::new([[T::zero(); 3]; 3]);
+    for (mass, r) in masses {
+        let r_r = r.iter().fold(T::zero(), |sum, r_i| sum + *r_i * *r_i);
+        for (i, row) in inertia.data.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                let diagonal = if i == j { r_r } else { T::zero() };
+                *entry = *entry + *mass * (diagonal - r[i] * r[j]);
+            }
+        }
+    }
+    inertia
+}
+
+/// The generalized cross product of the `N - 1` vectors `vectors` in `N` dimensions, the vector `c` with
+/// `c · x = det[v₁, …, vₙ₋₁, x]` for every `x`, found by cofactor expansion along the last row.
+///
+/// It is orthogonal to each of `vectors`, with length the volume of the parallelotope they span, and is zero when
+/// they are linearly dependent. In three dimensions it is the usual cross product.
+/// The number of vectors `K` is checked to be `N - 1`, failing to compile otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{generalized_cross, ColumnVector};
+/// let x = ColumnVector::<3,f64>::new([[1.0], [0.0], [0.0]]);
+/// let y = ColumnVector::<3,f64>::new([[0.0], [1.0], [0.0]]);
+/// assert_eq!(generalized_cross(&[x, y]), ColumnVector::new([[0.0], [0.0], [1.0]]));
+///
+/// let u = ColumnVector::<4,f64>::new([[1.0], [1.0], [0.0], [0.0]]);
+/// let v = ColumnVector::<4,f64>::new([[0.0], [1.0], [1.0], [0.0]]);
+/// let w = ColumnVector::<4,f64>::new([[0.0], [0.0], [1.0], [1.0]]);
+/// let c = generalized_cross(&[u, v, w]);
+/// assert!([u, v, w].iter().all(|vector| vector.dot(&c).abs() < 1e-12));
+/// ```
+pub fn generalized_cross<const N: usize, const K: usize, T: MatrixEntry + Float>(
+    vectors: &[ColumnVector<N, T>; K],
+) -> ColumnVector<N, T> {
+    const { dim::assert_minor(K, N, "the generalized cross product takes N - 1 vectors") };
+    let mut cross = ColumnVector::<N, T>::new([[T::zero()]; N]);
+    for (i, [entry]) in cross.data.iter_mut().enumerate() {
+        let mut minor = SquareMatrix::<K, T>::new([[T::zero(); K]; K]);
+        for (minor_row, vector) in minor.data.iter_mut().zip(vectors) {
+            let kept = vector.data.iter().enumerate().filter(|(j, _)| *j != i);
+            for (minor_entry, (_, [vector_entry])) in minor_row.iter_mut().zip(kept) {
+                *minor_entry = *vector_entry;
+            }
+        }
+        let cofactor = Lu::new(&minor).det();
+        *entry = if (K + i) % 2 == 0 {
+            cofactor
+        } else {
+            -cofactor
+        };
+    }
+    cross
+}
+
+/// The orientation of the `N + 1` points `points` in `N` dimensions, `det[p₁ - p₀, …, pₙ - p₀]`, which is positive
+/// when the simplex they span is positively oriented, such as a counterclockwise triangle in the plane, negative when
+/// it is negatively oriented, and zero when the points are degenerate. Its magnitude is `N!` times the volume of the
+/// simplex. The number of points `P` is checked to be `N + 1`, failing to compile otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{orient, ColumnVector};
+/// let a = ColumnVector::<2,f64>::new([[0.0], [0.0]]);
+/// let b = ColumnVector::<2,f64>::new([[2.0], [0.0]]);
+/// let c = ColumnVector::<2,f64>::new([[0.0], [1.0]]);
+/// assert_eq!(orient(&[a, b, c]), 2.0);
+/// assert_eq!(orient(&[a, c, b]), -2.0);
+/// assert_eq!(orient(&[a, b, ColumnVector::new([[4.0], [0.0]])]), 0.0);
+/// ```
+pub fn orient<const N: usize, const P: usize, T: MatrixEntry + Float>(
+    points: &[ColumnVector<N, T>; P],
+) -> T {
+    const { dim::assert_sum(P, N, 1, "orientation takes N + 1 points") };
+    let Some((origin, rest)) = points.split_first() else {
+        return T::one();
+    };
+    let mut edges = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+    for (j, point) in rest.iter().enumerate() {
+        for (row, ([entry], [origin_entry])) in edges
+            .data
+            .iter_mut()
+            .zip(point.data.iter().zip(origin.data.iter()))
+        {
+            row[j] = *entry - *origin_entry;
+        }
+    }
+    Lu::new(&edges).det()
+}
@@ -0,0 +1,82 @@
+use std::ops::{Add, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::{dim, ColumnVector, MatrixEntry, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + One + Zero + Sub<Output = T>> SquareMatrix<N, T> {
+    /// The Hadamard matrix of size `N = 2ᴷ` given by Sylvester's construction `H₂ₙ = [[Hₙ, Hₙ], [Hₙ, -Hₙ]]`,
+    /// whose entry `(i, j)` is `-1` when `i` and `j` have an odd number of one bits in common, and `1` otherwise.
+    ///
+    /// Its rows are mutually orthogonal, with `HHᵀ = NI`. A size that is not `2ᴷ` fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let h = SquareMatrix::<4,i32>::hadamard::<2>();
+    /// assert_eq!(h, SquareMatrix::new([[1, 1, 1, 1], [1, -1, 1, -1], [1, 1, -1, -1], [1, -1, -1, 1]]));
+    /// assert_eq!(h * h.transpose(), SquareMatrix::new([[4, 0, 0, 0], [0, 4, 0, 0], [0, 0, 4, 0], [0, 0, 0, 4]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use malg::SquareMatrix;
+    /// let h = SquareMatrix::<6,i32>::hadamard::<2>();
+    /// ```
+    pub fn hadamard<const K: u32>() -> Self {
+        const {
+            dim::assert_power(
+                N,
+                2,
+                K,
+                "Sylvester's construction gives matrices of size 2^K",
+            )
+        };
+        let mut h = SquareMatrix::<N, T>::zero();
+        for (i, row) in h.data.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = if (i & j).count_ones() % 2 == 0 {
+                    T::one()
+                } else {
+                    T::zero() - T::one()
+                };
+            }
+        }
+        h
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Add<Output = T> + Sub<Output = T>> ColumnVector<N, T> {
+    /// The Walsh–Hadamard transform `Hx` of `self` by the [Hadamard matrix](SquareMatrix::hadamard) `H`, computed by
+    /// the fast transform in `O(N log N)` sums and differences without forming `H`.
+    ///
+    /// The transform is unnormalized, so applying it twice multiplies `self` by `N`.
+    /// A length that is not a power of two fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{ColumnVector, SquareMatrix};
+    /// let x = ColumnVector::<4,i32>::new([[1], [0], [1], [0]]);
+    /// let transformed = x.walsh_hadamard();
+    ///
+    /// assert_eq!(transformed, ColumnVector::new([[2], [2], [0], [0]]));
+    /// assert_eq!(transformed, SquareMatrix::<4,i32>::hadamard::<2>() * x);
+    /// assert_eq!(transformed.walsh_hadamard(), x * 4);
+    /// ```
+    pub fn walsh_hadamard(&self) -> Self {
+        const { dim::assert_power_of_two(N, "the fast transform needs a power of two length") };
+        let mut x = *self;
+        let mut half = 1;
+        while half < N {
+            for block in x.data.chunks_exact_mut(2 * half) {
+                let (low, high) = block.split_at_mut(half);
+                for ([a], [b]) in low.iter_mut().zip(high.iter_mut()) {
+                    (*a, *b) = (*a + *b, *a - *b);
+                }
+            }
+            half *= 2;
+        }
+        x
+    }
+}
@@ -0,0 +1,103 @@
+use std::cell::{Cell, RefCell};
+
+/// Kind of matrix operation reported by the `instrumentation` feature.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Operation {
+    /// Product of two matrices.
+    Mul,
+    /// Swap of two rows.
+    SwapRows,
+    /// Scaling of a row by a scalar.
+    ScaleRow,
+    /// Addition of a multiple of one row to another.
+    AddRows,
+}
+
+/// A matrix operation that has been performed, and the number of scalar floating point operations it took.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub struct OperationEvent {
+    operation: Operation,
+    flops: u64,
+}
+
+impl OperationEvent {
+    /// Kind of operation performed.
+    pub fn get_operation(&self) -> Operation {
+        self.operation
+    }
+    /// Number of scalar additions and multiplications performed.
+    pub fn get_flops(&self) -> u64 {
+        self.flops
+    }
+}
+
+type OperationHook = Box<dyn FnMut(&OperationEvent)>;
+
+thread_local! {
+    static FLOP_COUNT: Cell<u64> = const { Cell::new(0) };
+    static OPERATION_HOOK: RefCell<Option<OperationHook>> = const { RefCell::new(None) };
+}
+
+/// Number of scalar floating point operations performed by matrix operations on this thread
+/// since it started or since [`reset_flop_count`] was last called.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{flop_count, reset_flop_count, Matrix};
+/// let a = Matrix::<2,3,f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// reset_flop_count();
+/// let _ = a * a.transpose();
+/// assert_eq!(flop_count(), 2 * 2 * 3 * 2);
+/// ```
+pub fn flop_count() -> u64 {
+    FLOP_COUNT.with(Cell::get)
+}
+
+/// Reset the count of [`flop_count`] on this thread to zero.
+pub fn reset_flop_count() {
+    FLOP_COUNT.with(|count| count.set(0));
+}
+
+/// Call `hook` with every matrix operation subsequently performed on this thread, replacing any previous hook.
+///
+/// Matrix operations performed inside `hook` are counted by [`flop_count`] but not reported to `hook`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{cell::RefCell, rc::Rc};
+/// # use malg::{clear_operation_hook, set_operation_hook, Matrix, Operation, RowOps};
+/// let operations = Rc::new(RefCell::new(Vec::new()));
+/// let recorded = Rc::clone(&operations);
+/// set_operation_hook(move |event| recorded.borrow_mut().push(event.get_operation()));
+///
+/// let mut a = Matrix::<2,2,f64>::new([[0.0, 1.0], [2.0, 4.0]]);
+/// a.transform_to_row_echelon_form();
+/// clear_operation_hook();
+///
+/// assert_eq!(
+///     *operations.borrow(),
+///     [Operation::SwapRows, Operation::ScaleRow, Operation::SwapRows, Operation::ScaleRow]
+/// );
+/// ```
+pub fn set_operation_hook(hook: impl FnMut(&OperationEvent) + 'static) {
+    OPERATION_HOOK.with(|current| *current.borrow_mut() = Some(Box::new(hook)));
+}
+
+/// Stop reporting matrix operations on this thread to the hook set by [`set_operation_hook`].
+pub fn clear_operation_hook() {
+    OPERATION_HOOK.with(|current| *current.borrow_mut() = None);
+}
+
+/// Count the `flops` of `operation` and report it to the hook of this thread, if any.
+pub(crate) fn record(operation: Operation, flops: u64) {
+    FLOP_COUNT.with(|count| count.set(count.get() + flops));
+    let hook = OPERATION_HOOK.with(|current| current.borrow_mut().take());
+    if let Some(mut hook) = hook {
+        hook(&OperationEvent { operation, flops });
+        OPERATION_HOOK.with(|current| {
+            current.borrow_mut().get_or_insert(hook);
+        });
+    }
+}
@@ -0,0 +1,160 @@
+use num_traits::{CheckedMul, CheckedSub, One, Zero};
+use std::ops::Div;
+
+use crate::{Matrix, MatrixEntry, MatrixError, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + Into<i128>> SquareMatrix<N, T> {
+    /// The exact determinant of an integer matrix, computed with fraction-free Bareiss elimination
+    /// after widening every entry to [`i128`]. Every intermediate value is a minor of `self`, and all divisions are exact.
+    /// If any intermediate value overflows [`i128`], get [`MatrixError::Overflow`] instead of a silently wrapped result.
+    ///
+    /// # Examples
+    ///
+    /// The determinant of an `i32` matrix need not fit in an `i32`,
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,i32>::new([[100_000, 1, 2], [3, 100_000, 4], [5, 6, 100_000]]);
+    /// assert_eq!(a.determinant_exact(), Ok(999_999_996_300_056));
+    /// ```
+    ///
+    /// but if it cannot fit in an `i128` either, an error is returned.
+    ///
+    /// ```
+    /// # use malg::{MatrixError, SquareMatrix};
+    /// let mut data = [[0i64; 8]; 8];
+    /// for i in 0..8 {
+    ///     data[i][i] = i64::MAX;
+    /// }
+    /// assert_eq!(SquareMatrix::new(data).determinant_exact(), Err(MatrixError::Overflow));
+    /// ```
+    pub fn determinant_exact(&self) -> Result<i128, MatrixError> {
+        let mut a: [[i128; N]; N] = self.data.map(|row| row.map(Into::into));
+        let mut sign = 1;
+        let mut previous_pivot = 1;
+        for k in 0..N {
+            if a[k][k] == 0 {
+                match (k + 1..N).find(|&i| a[i][k] != 0) {
+                    Some(i) => {
+                        a.swap(i, k);
+                        sign = -sign;
+                    }
+                    None => return Ok(0),
+                }
+            }
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    let cross = a[i][j]
+                        .checked_mul(a[k][k])
+                        .zip(a[i][k].checked_mul(a[k][j]))
+                        .and_then(|(x, y)| x.checked_sub(y))
+                        .ok_or(MatrixError::Overflow)?;
+                    a[i][j] = cross / previous_pivot;
+                }
+            }
+            previous_pivot = a[k][k];
+        }
+        match N {
+            0 => Ok(1),
+            _ => a[N - 1][N - 1]
+                .checked_mul(sign)
+                .ok_or(MatrixError::Overflow),
+        }
+    }
+}
+
+impl<
+        const M: usize,
+        const N: usize,
+        T: MatrixEntry + Zero + One + CheckedMul + CheckedSub + Div<Output = T>,
+    > Matrix<M, N, T>
+{
+    /// A row echelon form of an integer matrix that stays in the integers, computed with fraction-free Bareiss elimination.
+    ///
+    /// Rather than dividing each pivot row by its pivot, rows below the pivot are cross-multiplied by the pivot
+    /// and divided by the previous pivot, which is always exact. Each entry of the result is a minor of `self`,
+    /// so pivots are not normalized to one, and the last pivot of a square matrix is its determinant up to sign.
+    /// If any intermediate value overflows `T`, get [`MatrixError::Overflow`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,4,i32>::new([[2, 4, 1, 3], [4, 8, 3, 7], [6, 13, 4, 9]]);
+    /// let echelon = a.integer_row_echelon_form();
+    /// assert_eq!(echelon, Ok(Matrix::new([[2, 4, 1, 3], [0, 2, 2, 0], [0, 0, 2, 2]])));
+    /// ```
+    ///
+    /// Overflowing the entry type is reported.
+    ///
+    /// ```
+    /// # use malg::{Matrix, MatrixError};
+    /// let a = Matrix::<2,2,i8>::new([[100, 1], [1, 100]]);
+    /// assert_eq!(a.integer_row_echelon_form(), Err(MatrixError::Overflow));
+    /// ```
+    pub fn integer_row_echelon_form(&self) -> Result<Matrix<M, N, T>, MatrixError> {
+        let mut a = self.data;
+        let mut previous_pivot = T::one();
+        let mut i = 0;
+        for j in 0..N {
+            let Some(k) = (i..M).find(|&k| !a[k][j].is_zero()) else {
+                continue;
+            };
+            a.swap(i, k);
+            let pivot = a[i][j];
+            let (upper, lower) = a.split_at_mut(i + 1);
+            let pivot_row = &upper[i];
+            for row in lower.iter_mut() {
+                let factor = row[j];
+                for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(j + 1) {
+                    let cross = entry
+                        .checked_mul(&pivot)
+                        .zip(factor.checked_mul(pivot_entry))
+                        .and_then(|(x, y)| x.checked_sub(&y))
+                        .ok_or(MatrixError::Overflow)?;
+                    *entry = cross / previous_pivot;
+                }
+                row[j] = T::zero();
+            }
+            previous_pivot = pivot;
+            i += 1;
+            if i == M {
+                break;
+            }
+        }
+        Ok(Matrix::new(a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check exact determinants of [`Matrix`]s needing row swaps, and of singular matrices
+    #[test]
+    fn check_determinant_exact_with_row_swaps() -> Result<(), Box<dyn Error>> {
+        let swap = SquareMatrix::<2, i8>::new([[0, 1], [1, 0]]);
+        assert_eq!(swap.determinant_exact()?, -1);
+        let a = SquareMatrix::<3, i32>::new([[0, 2, 1], [1, -1, 4], [3, 5, -2]]);
+        assert_eq!(a.determinant_exact()?, 36);
+        let singular = SquareMatrix::<3, u8>::new([[1, 2, 3], [2, 4, 6], [1, 0, 1]]);
+        assert_eq!(singular.determinant_exact()?, 0);
+        Ok(())
+    }
+
+    /// Check the last pivot of the integer row echelon form of a square [`Matrix`] is its determinant up to sign
+    #[test]
+    fn check_integer_row_echelon_form_last_pivot() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, i32>::new([[0, 2, 1], [1, -1, 4], [3, 5, -2]]);
+        let echelon = a.integer_row_echelon_form()?;
+        assert_eq!(
+            i128::from(echelon.as_slice()[2][2]).abs(),
+            a.determinant_exact()?.abs()
+        );
+        assert_eq!(echelon.as_slice()[1][0], 0);
+        assert_eq!(echelon.as_slice()[2][1], 0);
+        Ok(())
+    }
+}
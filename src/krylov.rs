@@ -0,0 +1,91 @@
+use std::ops::{Add, Mul};
+
+use num_traits::Float;
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + Add<Output = T> + Mul<Output = T>> SquareMatrix<N, T> {
+    /// The Krylov matrix `[v, Av, A²v, …, Aᴷ⁻¹v]` whose `K` columns span the Krylov subspace of `self` and `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,i32>::new([[1, 1], [0, 2]]);
+    /// let v = Matrix::<2,1,i32>::new([[0], [1]]);
+    ///
+    /// let k = a.krylov_basis::<3>(&v);
+    ///
+    /// assert_eq!(k, Matrix::<2,3,i32>::new([[0, 1, 3], [1, 2, 4]]));
+    /// ```
+    pub fn krylov_basis<const K: usize>(&self, v: &Matrix<N, 1, T>) -> Matrix<N, K, T> {
+        let mut basis = Matrix::<N, K, T>::new([[T::default(); K]; N]);
+        let mut w = *v;
+        for k in 0..K {
+            for i in 0..N {
+                basis.data[i][k] = w.data[i][0];
+            }
+            if k + 1 < K {
+                w = *self * w;
+            }
+        }
+        basis
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// An orthonormal basis of the `K`-dimensional Krylov subspace of `self` and `v`, as the columns of an `N`-by-`K` matrix,
+    /// computed by the Arnoldi iteration with modified Gram–Schmidt orthogonalization.
+    /// If `v` is zero, or the Krylov subspace has dimension less than `K`, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]);
+    /// let v = Matrix::<3,1,f64>::new([[1.0], [0.0], [0.0]]);
+    ///
+    /// let q = a.orthonormal_krylov_basis::<2>(&v).unwrap();
+    ///
+    /// assert_eq!(q, Matrix::<3,2,f64>::new([[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]));
+    /// ```
+    ///
+    /// The Krylov subspace of an eigenvector is one-dimensional.
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[2.0, 0.0], [0.0, 3.0]]);
+    /// let v = Matrix::<2,1,f64>::new([[1.0], [0.0]]);
+    /// assert!(a.orthonormal_krylov_basis::<2>(&v).is_none());
+    /// ```
+    pub fn orthonormal_krylov_basis<const K: usize>(
+        &self,
+        v: &Matrix<N, 1, T>,
+    ) -> Option<Matrix<N, K, T>> {
+        let mut basis = Matrix::<N, K, T>::new([[T::zero(); K]; N]);
+        let mut w = *v;
+        for k in 0..K {
+            let scale = w.frobenius_norm();
+            for j in 0..k {
+                let projection =
+                    (0..N).fold(T::zero(), |sum, i| sum + basis.data[i][j] * w.data[i][0]);
+                for i in 0..N {
+                    w.data[i][0] = w.data[i][0] - projection * basis.data[i][j];
+                }
+            }
+            let norm = w.frobenius_norm();
+            if norm <= T::epsilon() * scale || norm.is_zero() {
+                return None;
+            }
+            for i in 0..N {
+                basis.data[i][k] = w.data[i][0] / norm;
+            }
+            let mut q_k = Matrix::<N, 1, T>::new([[T::zero()]; N]);
+            for i in 0..N {
+                q_k.data[i][0] = basis.data[i][k];
+            }
+            w = *self * q_k;
+        }
+        Some(basis)
+    }
+}
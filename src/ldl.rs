@@ -0,0 +1,125 @@
+use num_traits::{Float, One, Zero};
+
+use crate::{
+    back_substitution, forward_substitution, Factorization, Matrix, MatrixEntry, SquareMatrix,
+};
+
+/// LDLᵀ factorization `A = LDLᵀ` of a symmetric `N`-by-`N` matrix `A`,
+/// where `L` is unit lower triangular and `D` is diagonal.
+///
+/// Unlike [`Cholesky`](crate::Cholesky), it takes no square roots, and factorizes indefinite matrices
+/// as long as no pivot is zero.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Ldl<const N: usize, T: MatrixEntry> {
+    l: SquareMatrix<N, T>,
+    d: SquareMatrix<N, T>,
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Ldl<N, T> {
+    /// Factorize the symmetric matrix `a`, reading only its lower triangle.
+    /// If a pivot is zero, as for `[[0, 1], [1, 0]]`, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Ldl, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[4.0, 2.0], [2.0, -3.0]]);
+    /// let ldl = Ldl::new(&a).unwrap();
+    /// assert_eq!(*ldl.get_l(), SquareMatrix::new([[1.0, 0.0], [0.5, 1.0]]));
+    /// assert_eq!(*ldl.get_d(), SquareMatrix::new([[4.0, 0.0], [0.0, -4.0]]));
+    /// ```
+    ///
+    /// ```
+    /// # use malg::{Ldl, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[0.0, 1.0], [1.0, 0.0]]);
+    /// assert!(Ldl::new(&a).is_none());
+    /// ```
+    pub fn new(a: &SquareMatrix<N, T>) -> Option<Ldl<N, T>> {
+        let mut l = SquareMatrix::<N, T>::one();
+        let mut d = SquareMatrix::<N, T>::zero();
+        for j in 0..N {
+            let mut pivot = a.data[j][j];
+            for k in 0..j {
+                pivot = pivot - l.data[j][k] * l.data[j][k] * d.data[k][k];
+            }
+            if pivot.is_zero() || pivot.is_nan() {
+                return None;
+            }
+            d.data[j][j] = pivot;
+            for i in j + 1..N {
+                let mut sum = a.data[i][j];
+                for k in 0..j {
+                    sum = sum - l.data[i][k] * l.data[j][k] * d.data[k][k];
+                }
+                l.data[i][j] = sum / pivot;
+            }
+        }
+        Some(Ldl::<N, T> { l, d })
+    }
+    /// Unit lower triangular factor `L`.
+    pub fn get_l(&self) -> &SquareMatrix<N, T> {
+        &self.l
+    }
+    /// Diagonal factor `D`.
+    pub fn get_d(&self) -> &SquareMatrix<N, T> {
+        &self.d
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Factorization<N> for Ldl<N, T> {
+    type Output = T;
+    /// Solve `Ax = b` by forward substitution of `Lz = b`, scaling `y = D⁻¹z`, and back substitution of `Lᵀx = y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Ldl, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, 2.0, 0.0], [2.0, 1.0, 1.0], [0.0, 1.0, 1.0]]);
+    /// let b = Matrix::<3,1,f64>::new([[4.0], [4.0], [2.0]]);
+    /// let x = Ldl::new(&a).unwrap().solve_column(&b).unwrap();
+    /// assert_eq!(x, Matrix::<3,1,f64>::new([[1.0], [1.0], [1.0]]));
+    /// ```
+    fn solve_column(&self, b: &Matrix<N, 1, T>) -> Option<Matrix<N, 1, T>> {
+        let mut y = forward_substitution(&self.l, b, true)?;
+        for (i, [entry]) in y.data.iter_mut().enumerate() {
+            *entry = *entry / self.d.data[i][i];
+        }
+        back_substitution(&self.l.transpose(), &y)
+    }
+    /// The determinant of `A`, the product of the diagonal of `D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Ldl, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[4.0, 2.0], [2.0, -3.0]]);
+    /// assert_eq!(Ldl::new(&a).unwrap().det(), -16.0);
+    /// ```
+    fn det(&self) -> T {
+        (0..N).fold(T::one(), |det, i| det * self.d.data[i][i])
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The LDLᵀ factorization of a symmetric matrix, reading only the lower triangle of `self`.
+    /// If a pivot is zero, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// An indefinite system, which has no Cholesky factorization,
+    ///
+    /// ```
+    /// # use malg::{Factorization, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 1.0]]);
+    /// assert_eq!(a.cholesky(), None);
+    ///
+    /// let ldl = a.ldl().unwrap();
+    /// assert_eq!(*ldl.get_l() * *ldl.get_d() * ldl.get_l().transpose(), a);
+    ///
+    /// let x = ldl.solve(&Matrix::<2,1,f64>::new([[3.0], [3.0]])).unwrap();
+    /// assert_eq!(x, Matrix::new([[1.0], [1.0]]));
+    /// ```
+    pub fn ldl(&self) -> Option<Ldl<N, T>> {
+        Ldl::new(self)
+    }
+}
@@ -0,0 +1,248 @@
+use num_traits::Float;
+
+use crate::{dim, ColumnVector, Factorization, Lu, Matrix, MatrixEntry, Qr, RowOps, SquareMatrix};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, T> {
+    /// The `x` minimizing the sum of squared residuals `‖b - Ax‖²` of the overdetermined system `Ax ≈ b`,
+    /// and the norm `‖b - Ax‖` of the residual at the minimum.
+    ///
+    /// The system is solved through the QR factorization `A = QR` as `R₁x = Q₁ᵀb`, where `R₁` is the top square block
+    /// of `R` and `Q₁` the first `N` columns of `Q`, which avoids squaring the condition number as the normal equations
+    /// do. The residual norm is that of the remaining entries of `Qᵀb`. If the columns of `self` are linearly
+    /// dependent, so that the minimizer is not unique, get [`None`] instead. Systems with fewer equations than
+    /// unknowns fail to compile.
+    ///
+    /// # Examples
+    ///
+    /// Fit the line `y = c + mx` through four points,
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<4,2,f64>::new([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    /// let b = Matrix::<4,1,f64>::new([[1.0], [2.0], [2.0], [4.0]]);
+    ///
+    /// let (x, residual_norm) = a.least_squares(&b).unwrap();
+    ///
+    /// assert!((x - Matrix::new([[0.9], [0.9]])).frobenius_norm() < 1e-12);
+    /// assert!((residual_norm - (b - a * x).frobenius_norm()).abs() < 1e-12);
+    /// ```
+    ///
+    /// but repeated columns do not determine a unique fit.
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,f64>::new([[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]);
+    /// let b = Matrix::<3,1,f64>::new([[1.0], [2.0], [2.0]]);
+    /// assert_eq!(a.least_squares(&b), None);
+    /// ```
+    pub fn least_squares(&self, b: &Matrix<M, 1, T>) -> Option<(Matrix<N, 1, T>, T)> {
+        const { dim::assert_at_least(M, N, "least squares needs at least as many rows as columns") };
+        let qr = Qr::new(self);
+        let qtb = qr.get_q().transpose() * *b;
+        let r_1 = qr.get_r().submatrix::<N, N>(0, 0);
+        let rhs = qtb.submatrix::<N, 1>(0, 0);
+        if r_1.as_slice().iter().enumerate().any(|(i, row)| {
+            row[i].abs() <= T::epsilon() * T::from(M).unwrap_or_else(T::one) * self.frobenius_norm()
+        }) {
+            return None;
+        }
+        let x = r_1.solve_upper_triangular(&rhs)?;
+        let residual_norm = qtb
+            .as_slice()
+            .iter()
+            .skip(N)
+            .fold(T::zero(), |sum, [entry]| sum + *entry * *entry)
+            .sqrt();
+        Some((x, residual_norm))
+    }
+
+    /// The `x` minimizing the weighted sum of squared residuals `Σ wᵢ(b - Ax)ᵢ²`,
+    /// found by solving the normal equations `AᵀWAx = AᵀWb` where `W` is the diagonal matrix of `weights`.
+    /// If the normal equations are singular, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Fit the line `y = c + mx` through three points, ignoring the outlier at `x = 3`,
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<4,2,f64>::new([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    /// let b = Matrix::<4,1,f64>::new([[1.0], [3.0], [5.0], [100.0]]);
+    ///
+    /// let x = a.weighted_least_squares(&b, &[1.0, 1.0, 1.0, 0.0]).unwrap();
+    ///
+    /// assert!((*x.get_entry(0,0).unwrap() - 1.0).abs() < 1e-12);
+    /// assert!((*x.get_entry(1,0).unwrap() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn weighted_least_squares(
+        &self,
+        b: &Matrix<M, 1, T>,
+        weights: &[T; M],
+    ) -> Option<Matrix<N, 1, T>> {
+        let mut weighted_a = *self;
+        let mut weighted_b = *b;
+        for (i, weight) in weights.iter().enumerate() {
+            weighted_a.scale_row(i, *weight);
+            weighted_b.scale_row(i, *weight);
+        }
+        let a_t = self.transpose();
+        solve_normal_equations(a_t * weighted_a, a_t * weighted_b)
+    }
+
+    /// The `x` minimizing the regularized sum of squares `‖b - Ax‖² + λ‖x‖²`,
+    /// found by solving the normal equations `(AᵀA + λI)x = Aᵀb`.
+    /// If the normal equations are singular, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 0.0], [0.0, 1.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[2.0], [4.0]]);
+    ///
+    /// let x = a.ridge_solve(&b, 1.0).unwrap();
+    ///
+    /// assert_eq!(x, Matrix::<2,1,f64>::new([[1.0], [2.0]]));
+    /// ```
+    pub fn ridge_solve(&self, b: &Matrix<M, 1, T>, lambda: T) -> Option<Matrix<N, 1, T>> {
+        let a_t = self.transpose();
+        let mut normal = a_t * *self;
+        for i in 0..N {
+            normal.data[i][i] = normal.data[i][i] + lambda;
+        }
+        solve_normal_equations(normal, a_t * *b)
+    }
+
+    /// The orthogonal projection `P = A(AᵀA)⁻¹Aᵀ` onto the column space of `self`, so that `Pb` is the closest point
+    /// to `b` that is a combination of the columns, the fitted values of the least squares problem `Ax ≈ b`.
+    ///
+    /// If the columns are linearly dependent, `AᵀA` is singular and the pseudo-inverse projection `AA⁺` is used instead,
+    /// built from an orthonormal basis of the columns found by modified Gram–Schmidt. Columns whose component
+    /// orthogonal to the previous columns is negligible relative to the norm of `self` count as dependent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = Matrix::<3,2,f64>::new([[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]);
+    /// assert_eq!(a.projection_onto_columns(), SquareMatrix::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]));
+    ///
+    /// // Repeated columns span a line, so the projection falls back to the pseudo-inverse.
+    /// let dependent = Matrix::<2,2,f64>::new([[1.0, 1.0], [1.0, 1.0]]);
+    /// let p = dependent.projection_onto_columns();
+    /// assert!((p - SquareMatrix::new([[0.5, 0.5], [0.5, 0.5]])).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn projection_onto_columns(&self) -> SquareMatrix<M, T> {
+        let (basis, rank) = self.column_space_basis();
+        if rank == N {
+            let a_t = self.transpose();
+            if let Some(x) = Lu::new(&(a_t * *self)).solve(&a_t) {
+                return *self * x;
+            }
+        }
+        let mut projection = SquareMatrix::<M, T>::new([[T::zero(); M]; M]);
+        for q in basis.iter().take(rank) {
+            for (row, q_i) in projection.data.iter_mut().zip(q) {
+                for (entry, q_j) in row.iter_mut().zip(q) {
+                    *entry = *entry + *q_i * *q_j;
+                }
+            }
+        }
+        projection
+    }
+
+    /// The orthogonal projection `I - P` onto the orthogonal complement of the column space of `self`,
+    /// where `P` is the [projection onto the columns](Matrix::projection_onto_columns), so that `(I - P)b` is the
+    /// least squares residual of `Ax ≈ b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,1,f64>::new([[1.0], [1.0], [1.0]]);
+    /// let b = Matrix::<3,1,f64>::new([[1.0], [2.0], [6.0]]);
+    ///
+    /// let residual = a.orthogonal_complement_projection() * b;
+    ///
+    /// // Removing the mean leaves the deviations from it.
+    /// assert!((residual - Matrix::new([[-2.0], [-1.0], [3.0]])).frobenius_norm() < 1e-12);
+    /// assert!((a.transpose() * residual).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn orthogonal_complement_projection(&self) -> SquareMatrix<M, T> {
+        let mut complement = self.projection_onto_columns();
+        for (i, row) in complement.data.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = if i == j { T::one() } else { T::zero() } - *entry;
+            }
+        }
+        complement
+    }
+
+    /// An orthonormal basis of the column space of `self`, found by modified Gram–Schmidt orthogonalization of its
+    /// columns in turn, skipping columns whose component orthogonal to the basis so far is negligible
+    /// relative to the Frobenius norm of `self`.
+    ///
+    /// For a basis of the columns of `self` themselves, use [`Matrix::column_space`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,f64>::new([[3.0, 6.0, 0.0], [4.0, 8.0, 0.0], [0.0, 0.0, 2.0]]);
+    ///
+    /// let basis = a.orthonormal_column_space();
+    ///
+    /// assert_eq!(basis, vec![
+    ///     Matrix::new([[0.6], [0.8], [0.0]]),
+    ///     Matrix::new([[0.0], [0.0], [1.0]]),
+    /// ]);
+    /// ```
+    pub fn orthonormal_column_space(&self) -> Vec<ColumnVector<M, T>> {
+        let (basis, rank) = self.column_space_basis();
+        basis
+            .iter()
+            .take(rank)
+            .map(|q| Matrix::new(q.map(|q_i| [q_i])))
+            .collect()
+    }
+
+    /// An orthonormal basis of the column space of `self` by modified Gram–Schmidt, as the first `rank` columns
+    /// returned, skipping columns with a negligible component orthogonal to the columns before them.
+    fn column_space_basis(&self) -> ([[T; M]; N], usize) {
+        let tol = T::from(M.max(N)).unwrap_or_else(T::one) * T::epsilon() * self.frobenius_norm();
+        let mut basis = [[T::zero(); M]; N];
+        let mut rank = 0;
+        for j in 0..N {
+            let mut v = [T::zero(); M];
+            for (v_i, row) in v.iter_mut().zip(self.data.iter()) {
+                *v_i = row[j];
+            }
+            for q in basis.iter().take(rank) {
+                let dot = q
+                    .iter()
+                    .zip(v.iter())
+                    .fold(T::zero(), |sum, (q_i, v_i)| sum + *q_i * *v_i);
+                for (v_i, q_i) in v.iter_mut().zip(q) {
+                    *v_i = *v_i - dot * *q_i;
+                }
+            }
+            let norm = v
+                .iter()
+                .fold(T::zero(), |sum, v_i| sum + *v_i * *v_i)
+                .sqrt();
+            if norm > tol {
+                basis[rank] = v.map(|v_i| v_i / norm);
+                rank += 1;
+            }
+        }
+        (basis, rank)
+    }
+}
+
+/// Solve the square system `ax = b` by LU factorization with partial pivoting.
+fn solve_normal_equations<const N: usize, T: MatrixEntry + Float>(
+    a: SquareMatrix<N, T>,
+    b: Matrix<N, 1, T>,
+) -> Option<Matrix<N, 1, T>> {
+    Lu::new(&a).solve_column(&b)
+}
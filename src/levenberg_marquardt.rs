@@ -0,0 +1,165 @@
+use num_traits::Float;
+
+use crate::{Cholesky, ColumnVector, Factorization, Matrix, MatrixEntry, MatrixError};
+
+/// Stopping criteria and initial damping for [`levenberg_marquardt`].
+///
+/// # Examples
+///
+/// ```
+/// # use malg::LevenbergMarquardtOptions;
+/// let options = LevenbergMarquardtOptions::<f64>::new().max_iterations(50).tol(1e-10);
+/// assert_eq!(options.get_max_iterations(), 50);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct LevenbergMarquardtOptions<T> {
+    max_iterations: usize,
+    tol: T,
+    damping: T,
+}
+
+impl<T: Float> LevenbergMarquardtOptions<T> {
+    /// Options allowing 100 iterations with a tolerance of `√ε` and an initial damping of `10⁻³`.
+    pub fn new() -> Self {
+        LevenbergMarquardtOptions {
+            max_iterations: 100,
+            tol: T::epsilon().sqrt(),
+            damping: T::from(1e-3).unwrap_or_else(T::epsilon),
+        }
+    }
+    /// Give up after `max_iterations` iterations.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+    /// Stop once the gradient `Jᵀr` is within `tol` of zero in every entry, or a step changes the parameters by less
+    /// than `tol` relative to their size.
+    pub fn tol(mut self, tol: T) -> Self {
+        self.tol = tol;
+        self
+    }
+    /// Start with the damping parameter `λ = damping`, where small values start close to Gauss–Newton steps and large
+    /// values close to short gradient descent steps.
+    pub fn damping(mut self, damping: T) -> Self {
+        self.damping = damping;
+        self
+    }
+    /// The iteration limit.
+    pub fn get_max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+    /// The convergence tolerance.
+    pub fn get_tol(&self) -> T {
+        self.tol
+    }
+    /// The initial damping parameter.
+    pub fn get_damping(&self) -> T {
+        self.damping
+    }
+}
+
+impl<T: Float> Default for LevenbergMarquardtOptions<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The parameters `x` minimizing the sum of squared residuals `‖r(x)‖²` of `M` residuals in `N` parameters,
+/// starting from `x0`, and the norm `‖r(x)‖` of the residual at the minimum.
+///
+/// Each iteration solves the damped normal equations `(JᵀJ + λI)δ = -Jᵀr` by Cholesky factorization,
+/// where `r = residual(x)` and `J = jacobian(x)`, whose entry `(i, j)` is `∂rᵢ/∂xⱼ`. A step that reduces the sum of
+/// squares is taken and `λ` divided by ten, moving towards Gauss–Newton steps; otherwise `λ` is multiplied by ten,
+/// moving towards short gradient descent steps. A Jacobian can be estimated with
+/// [`numerical_jacobian`](crate::numerical_jacobian) when no closed form is at hand. If the `options` stopping criteria
+/// are not met within its iteration limit, get [`MatrixError::NotConverged`] instead.
+///
+/// # Examples
+///
+/// Fit the curve `y = a·exp(bt)` to five samples,
+///
+/// ```
+/// # use malg::{levenberg_marquardt, LevenbergMarquardtOptions, Matrix};
+/// let t = [0.0, 1.0, 2.0, 3.0, 4.0];
+/// let y = t.map(|t: f64| 2.0 * (0.5 * t).exp());
+///
+/// let residual = |[a, b]: &[f64; 2]| std::array::from_fn(|i| a * (b * t[i]).exp() - y[i]);
+/// let jacobian = |[a, b]: &[f64; 2]| {
+///     Matrix::<5,2,f64>::new(std::array::from_fn(|i| {
+///         let e = (b * t[i]).exp();
+///         [e, a * t[i] * e]
+///     }))
+/// };
+///
+/// let (x, residual_norm) =
+///     levenberg_marquardt(residual, jacobian, &[1.0, 0.0], &LevenbergMarquardtOptions::new()).unwrap();
+///
+/// assert!((x[0] - 2.0).abs() < 1e-8 && (x[1] - 0.5).abs() < 1e-8);
+/// assert!(residual_norm < 1e-8);
+/// ```
+///
+/// or find the point nearest three others, with a finite difference Jacobian.
+///
+/// ```
+/// # use malg::{levenberg_marquardt, numerical_jacobian, LevenbergMarquardtOptions};
+/// let residual = |[x, y]: &[f64; 2]| [x - 1.0, y - 2.0, x + y - 6.0];
+/// let jacobian = |x: &[f64; 2]| numerical_jacobian(residual, x, 1e-6);
+///
+/// let (x, _) = levenberg_marquardt(residual, jacobian, &[0.0, 0.0], &Default::default()).unwrap();
+///
+/// assert!((x[0] - 2.0).abs() < 1e-6 && (x[1] - 3.0).abs() < 1e-6);
+/// ```
+pub fn levenberg_marquardt<const M: usize, const N: usize, T: MatrixEntry + Float>(
+    residual: impl Fn(&[T; N]) -> [T; M],
+    jacobian: impl Fn(&[T; N]) -> Matrix<M, N, T>,
+    x0: &[T; N],
+    options: &LevenbergMarquardtOptions<T>,
+) -> Result<([T; N], T), MatrixError> {
+    let ten = T::from(10).unwrap_or_else(T::one);
+    let sum_of_squares = |r: &[T; M]| r.iter().fold(T::zero(), |sum, r_i| sum + *r_i * *r_i);
+    let mut x = *x0;
+    let mut r = residual(&x);
+    let mut cost = sum_of_squares(&r);
+    let mut lambda = options.damping;
+    for _ in 0..options.max_iterations {
+        let j = jacobian(&x);
+        let j_t = j.transpose();
+        let descent = j_t * ColumnVector::new(r.map(|r_i| [-r_i]));
+        if descent
+            .as_slice()
+            .iter()
+            .all(|[g_i]| g_i.abs() <= options.tol)
+        {
+            return Ok((x, cost.sqrt()));
+        }
+        let mut damped = j_t * j;
+        for (i, row) in damped.data.iter_mut().enumerate() {
+            row[i] = row[i] + lambda;
+        }
+        let Some(step) = Cholesky::new(&damped).and_then(|c| c.solve_column(&descent)) else {
+            lambda = lambda * ten;
+            continue;
+        };
+        let mut candidate = x;
+        for (x_i, [step_i]) in candidate.iter_mut().zip(step.as_slice()) {
+            *x_i = *x_i + *step_i;
+        }
+        let candidate_r = residual(&candidate);
+        let candidate_cost = sum_of_squares(&candidate_r);
+        if candidate_cost < cost {
+            let x_norm = x
+                .iter()
+                .fold(T::zero(), |sum, x_i| sum + *x_i * *x_i)
+                .sqrt();
+            let converged = step.frobenius_norm() <= options.tol * (x_norm + options.tol);
+            (x, r, cost) = (candidate, candidate_r, candidate_cost);
+            if converged {
+                return Ok((x, cost.sqrt()));
+            }
+            lambda = lambda / ten;
+        } else {
+            lambda = lambda * ten;
+        }
+    }
+    Err(MatrixError::NotConverged)
+}
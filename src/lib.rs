@@ -1,4 +1,20 @@
-use num_traits::{One, Zero};
+//! Matrix algebra in rust.
+//!
+//! # Custom scalars
+//!
+//! Entrywise operations accept any [`MatrixEntry`]. Row reduction and the methods built on it, such as
+//! [`RowOps::transform_to_row_echelon_form`], [`SquareMatrix::inverse`] and [`AugmentedMatrix::solve`], also
+//! require the scalar to implement [`CommutativeRing`]. Earlier versions did not, so a scalar type defined outside
+//! this crate must now opt in; for exact scalars an empty `impl CommutativeRing for MyScalar {}` is enough, as shown
+//! in the trait's documentation.
+
+#![cfg_attr(
+    malg_generic_const_exprs,
+    feature(generic_const_exprs),
+    allow(incomplete_features)
+)]
+
+use num_traits::{ConstZero, One, Zero};
 use std::{
     num::NonZeroUsize,
     ops::{Add, Div, Mul, Sub},
@@ -12,16 +28,247 @@ mod square_matrix;
 #[allow(unused_imports)]
 pub use square_matrix::*;
 
+mod vector;
+#[allow(unused_imports)]
+pub use vector::*;
+
 mod augmented_matrix;
 #[allow(unused_imports)]
 pub use augmented_matrix::*;
 
+mod column_operations;
+#[allow(unused_imports)]
+pub use column_operations::*;
+
+mod stacked_matrix;
+#[allow(unused_imports)]
+pub use stacked_matrix::*;
+
+mod least_squares;
+#[allow(unused_imports)]
+pub use least_squares::*;
+
+mod rotation;
+#[allow(unused_imports)]
+pub use rotation::*;
+
+mod decomposition;
+#[allow(unused_imports)]
+pub use decomposition::*;
+
+mod lu;
+#[allow(unused_imports)]
+pub use lu::*;
+
+mod qr;
+#[allow(unused_imports)]
+pub use qr::*;
+
+mod cholesky;
+#[allow(unused_imports)]
+pub use cholesky::*;
+
+mod ldl;
+#[allow(unused_imports)]
+pub use ldl::*;
+
+mod error;
+#[allow(unused_imports)]
+pub use error::*;
+
+mod schur_complement;
+#[allow(unused_imports)]
+pub use schur_complement::*;
+
+mod statistics;
+#[allow(unused_imports)]
+pub use statistics::*;
+
+mod bidiagonalization;
+#[allow(unused_imports)]
+pub use bidiagonalization::*;
+
+mod parts;
+#[allow(unused_imports)]
+pub use parts::*;
+
+mod structure;
+#[allow(unused_imports)]
+pub use structure::*;
+
+mod norms;
+#[allow(unused_imports)]
+pub use norms::*;
+
+mod matrix_equation;
+#[allow(unused_imports)]
+pub use matrix_equation::*;
+
+mod krylov;
+#[allow(unused_imports)]
+pub use krylov::*;
+
+mod equilibration;
+#[allow(unused_imports)]
+pub use equilibration::*;
+
+mod integer;
+#[allow(unused_imports)]
+pub use integer::*;
+
+mod transform_cache;
+#[allow(unused_imports)]
+pub use transform_cache::*;
+
+mod slicing;
+#[allow(unused_imports)]
+pub use slicing::*;
+
+mod formatting;
+#[allow(unused_imports)]
+pub use formatting::*;
+
+mod wire;
+#[allow(unused_imports)]
+pub use wire::*;
+
+mod geometry;
+#[allow(unused_imports)]
+pub use geometry::*;
+
+mod quaternion;
+#[allow(unused_imports)]
+pub use quaternion::*;
+
+mod residual;
+#[allow(unused_imports)]
+pub use residual::*;
+
+mod control;
+#[allow(unused_imports)]
+pub use control::*;
+
+mod stochastic;
+#[allow(unused_imports)]
+pub use stochastic::*;
+
+mod assignment;
+#[allow(unused_imports)]
+pub use assignment::*;
+
+mod paths;
+#[allow(unused_imports)]
+pub use paths::*;
+
+mod validation;
+#[allow(unused_imports)]
+pub use validation::*;
+
+mod updates;
+#[allow(unused_imports)]
+pub use updates::*;
+
+mod progress;
+#[allow(unused_imports)]
+pub use progress::*;
+
+mod precision;
+#[allow(unused_imports)]
+pub use precision::*;
+
+mod symmetric_eigen;
+#[allow(unused_imports)]
+pub use symmetric_eigen::*;
+
+mod eigen;
+#[allow(unused_imports)]
+pub use eigen::*;
+
+mod hadamard;
+#[allow(unused_imports)]
+pub use hadamard::*;
+
+mod cumulative;
+#[allow(unused_imports)]
+pub use cumulative::*;
+
+mod constrained;
+#[allow(unused_imports)]
+pub use constrained::*;
+
+mod rank;
+#[allow(unused_imports)]
+pub use rank::*;
+
+mod polar;
+#[allow(unused_imports)]
+pub use polar::*;
+
+mod svd;
+#[allow(unused_imports)]
+pub use svd::*;
+
+mod calculus;
+#[allow(unused_imports)]
+pub use calculus::*;
+
+mod levenberg_marquardt;
+#[allow(unused_imports)]
+pub use levenberg_marquardt::*;
+
+mod batch;
+#[allow(unused_imports)]
+pub use batch::*;
+
+mod expm;
+#[allow(unused_imports)]
+pub use expm::*;
+
+pub mod dim;
+
+pub mod test_matrices;
+
+#[cfg(feature = "instrumentation")]
+mod instrumentation;
+#[cfg(feature = "instrumentation")]
+#[allow(unused_imports)]
+pub use instrumentation::*;
+
+#[cfg(feature = "num-complex")]
+mod complex;
+#[cfg(feature = "num-complex")]
+#[allow(unused_imports)]
+pub use complex::*;
+
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+#[allow(unused_imports)]
+pub use random::*;
+
 /// Minimum trait bounds for a type to be extendable as a [`Matrix`].
 pub trait MatrixEntry: Copy + Default + PartialEq {}
 impl<T: Copy + Default + PartialEq> MatrixEntry for T {}
 
 /// `M`-by-`N` rectangular matrix with entries of type `T`.
+///
+/// With the `rkyv` feature, matrices can be archived with [rkyv](https://docs.rs/rkyv) and read in place without deserializing them.
+///
+/// ```
+/// # #[cfg(feature = "rkyv")]
+/// # {
+/// # use malg::{ArchivedMatrix, Matrix};
+/// let a = Matrix::<2,2,u32>::new([[1, 2], [3, 4]]);
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&a).unwrap();
+/// let archived = rkyv::access::<ArchivedMatrix<2,2,u32>, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap(), a);
+/// # }
+/// ```
 #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Matrix<const M: usize, const N: usize, T: MatrixEntry> {
     data: [[T; N]; M],
 }
@@ -160,10 +407,73 @@ impl<const M: usize, const N: usize, T: MatrixEntry> Matrix<M, N, T> {
         Matrix::<N, M, T>::new(transpose_data)
     }
 
+    /// The `P`-by-`Q` block of `self` whose upper-left entry is the (`i`, `j`)<sup>th</sup> entry, using zero-based indexing.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the block does not lie inside the matrix.
+    pub(crate) fn submatrix<const P: usize, const Q: usize>(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Matrix<P, Q, T> {
+        let mut block = [[T::default(); Q]; P];
+        for (block_row, row) in block.iter_mut().zip(&self.data[i..i + P]) {
+            block_row.copy_from_slice(&row[j..j + Q]);
+        }
+        Matrix::<P, Q, T>::new(block)
+    }
+
     /// Append the matrix `right` onto `self`, creating the augmented matrix `[self|right]`.
     pub fn augment<const P: usize>(&self, right: &Matrix<M, P, T>) -> AugmentedMatrix<M, N, P, T> {
         AugmentedMatrix::<M, N, P, T>::new(*self, *right)
     }
+
+    /// Place the matrix `below` under `self`, creating the stacked matrix with `self` on top of `below`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use malg::Matrix;
+    /// let a = Matrix::<2,2,u8>::new([[1,2],[3,4]]);
+    /// let b = Matrix::<1,2,u8>::new([[5,6]]);
+    /// let c = a.augment_below(&b);
+    /// assert_eq!(*c.get_top(), a);
+    /// assert_eq!(*c.get_bottom(), b);
+    /// ```
+    pub fn augment_below<const P: usize>(
+        &self,
+        below: &Matrix<P, N, T>,
+    ) -> StackedMatrix<M, P, N, T> {
+        StackedMatrix::<M, P, N, T>::new(*self, *below)
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry> Default for Matrix<M, N, T> {
+    /// The matrix with every entry the default of `T`, which is zero for numeric types.
+    ///
+    /// This makes matrices [`MatrixEntry`] types themselves, so that a matrix of matrices is a block matrix,
+    /// and the arithmetic of matrices multiplies blocks in the right order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,i32>::new([[1, 2], [3, 4]]);
+    /// let b = SquareMatrix::<2,i32>::new([[0, 1], [1, 0]]);
+    /// let i = SquareMatrix::<2,i32>::one();
+    ///
+    /// // The 4-by-4 matrix [[A, B], [0, I]] as a 2-by-2 matrix of 2-by-2 blocks.
+    /// let blocks = SquareMatrix::<2,SquareMatrix<2,i32>>::new([[a, b], [Default::default(), i]]);
+    /// let squared = blocks * blocks;
+    ///
+    /// assert_eq!(squared, SquareMatrix::new([[a * a, a * b + b], [SquareMatrix::default(), i]]));
+    /// assert_eq!(blocks * SquareMatrix::<2,SquareMatrix<2,i32>>::one(), blocks);
+    /// ```
+    fn default() -> Self {
+        Matrix::<M, N, T>::new([[T::default(); N]; M])
+    }
 }
 
 impl<const M: usize, const N: usize, T: MatrixEntry + Zero> Zero for Matrix<M, N, T> {
@@ -176,6 +486,22 @@ impl<const M: usize, const N: usize, T: MatrixEntry + Zero> Zero for Matrix<M, N
     }
 }
 
+impl<const M: usize, const N: usize, T: MatrixEntry + ConstZero> Matrix<M, N, T> {
+    /// The matrix with all entries equal to zero, usable in const contexts such as the initializers of statics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::Zero;
+    /// # use malg::Matrix;
+    /// static ORIGIN: Matrix<3, 1, f32> = Matrix::ZERO;
+    /// assert_eq!(ORIGIN, Matrix::zero());
+    /// ```
+    pub const ZERO: Self = Matrix {
+        data: [[T::ZERO; N]; M],
+    };
+}
+
 impl<const M: usize, const N: usize, T: MatrixEntry + Add<Output = T>> Add for Matrix<M, N, T> {
     type Output = Self;
     /// Natural definition of matrix addition for type `T`.
@@ -252,6 +578,8 @@ impl<
     /// ```
     type Output = Matrix<M, P, T>;
     fn mul(self, rhs: Matrix<N, P, T>) -> Self::Output {
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(instrumentation::Operation::Mul, 2 * (M * N * P) as u64);
         let mut product = [[T::default(); P]; M];
         for (i, row) in product.iter_mut().enumerate().take(M) {
             for (j, entry) in row.iter_mut().enumerate().take(P) {
@@ -288,6 +616,50 @@ impl<const M: usize, const N: usize, T: MatrixEntry + Mul<Output = T>> Mul<T> fo
     }
 }
 
+impl<const M: usize, const N: usize, T: MatrixEntry + Mul<Output = T>> Matrix<M, N, T> {
+    /// Scale row `i` of `self` by `scales[i]`, the product `DA` with the diagonal matrix `D` of `scales`,
+    /// without forming `D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,i32>::new([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.scale_rows_by(&[10, -1]), Matrix::new([[10, 20, 30], [-4, -5, -6]]));
+    /// ```
+    pub fn scale_rows_by(&self, scales: &[T; M]) -> Self {
+        let mut scaled = self.data;
+        for (row, scale) in scaled.iter_mut().zip(scales) {
+            for entry in row.iter_mut() {
+                *entry = *scale * *entry;
+            }
+        }
+        Matrix::<M, N, T>::new(scaled)
+    }
+
+    /// Scale column `j` of `self` by `scales[j]`, the product `AD` with the diagonal matrix `D` of `scales`,
+    /// without forming `D`.
+    ///
+    /// # Examples
+    ///
+    /// Convert the columns of a table from kilometres and hours to metres and seconds.
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.5, 2.0], [0.25, 0.5]]);
+    /// assert_eq!(a.scale_cols_by(&[1000.0, 3600.0]), Matrix::new([[1500.0, 7200.0], [250.0, 1800.0]]));
+    /// ```
+    pub fn scale_cols_by(&self, scales: &[T; N]) -> Self {
+        let mut scaled = self.data;
+        for row in scaled.iter_mut() {
+            for (entry, scale) in row.iter_mut().zip(scales) {
+                *entry = *entry * *scale;
+            }
+        }
+        Matrix::<M, N, T>::new(scaled)
+    }
+}
+
 impl<
         const M: usize,
         const N: usize,
@@ -313,6 +685,8 @@ impl<
     /// assert_eq!(a,b)
     /// ```
     fn swap_rows(&mut self, i: usize, j: usize) {
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(instrumentation::Operation::SwapRows, 0);
         self.data.swap(i, j);
     }
     /// Scale row `i` by scalar value `a` in place.
@@ -336,6 +710,8 @@ impl<
     /// ```
     ///
     fn scale_row(&mut self, i: usize, a: T) {
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(instrumentation::Operation::ScaleRow, N as u64);
         self.data[i]
             .iter_mut()
             .for_each(|entry| *entry = *entry * a);
@@ -361,11 +737,13 @@ impl<
     /// ```
     ///
     fn add_rows(&mut self, i: usize, j: usize, a: T) {
-        let add_row: Vec<T> = self.data[j].iter().map(|entry| *entry * a).collect();
+        #[cfg(feature = "instrumentation")]
+        instrumentation::record(instrumentation::Operation::AddRows, 2 * N as u64);
+        let add_row = self.data[j];
         self.data[i]
             .iter_mut()
             .zip(add_row)
-            .for_each(|(entry_i, entry_j): (&mut T, T)| *entry_i = *entry_i + entry_j);
+            .for_each(|(entry_i, entry_j): (&mut T, T)| *entry_i = *entry_i + entry_j * a);
     }
     /// The `ì`th row of `self`/
     ///
@@ -387,6 +765,18 @@ impl<
     fn get_row(&self, i: usize) -> Vec<T> {
         self.data[i].into()
     }
+    /// The entry in row `i` and column `j`, read without copying the row.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use malg::*;
+    /// let a = Matrix::<3,2,u8>::new([[1,2], [3,4], [5,6]]);
+    /// assert_eq!(a.get_row_entry(2, 1), 6)
+    /// ```
+    fn get_row_entry(&self, i: usize, j: usize) -> T {
+        self.data[i][j]
+    }
     /// The number of rows in the matrix, `M`.
     ///
     /// # Examples
@@ -421,3 +811,91 @@ impl<
         N
     }
 }
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Mul<Output = T> + Add<Output = T>> ColOps<T>
+    for Matrix<M, N, T>
+{
+    /// Swap columns `i` and `j` in place.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` or `j` are out of bounds. That is `i>=N` or `j>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use malg::*;
+    /// let mut a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<2,3,u8>::new([[1,3,2], [4,6,5]]);
+    ///
+    /// a.swap_cols(1,2);
+    ///
+    /// assert_eq!(a,b)
+    /// ```
+    fn swap_cols(&mut self, i: usize, j: usize) {
+        for row in self.data.iter_mut() {
+            row.swap(i, j);
+        }
+    }
+    /// Scale column `i` by scalar value `a` in place.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` is out of bounds. That is `i>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use malg::*;
+    /// let mut a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<2,3,u8>::new([[1,4,3], [4,10,6]]);
+    ///
+    /// a.scale_col(1,2);
+    ///
+    /// assert_eq!(a,b)
+    /// ```
+    fn scale_col(&mut self, i: usize, a: T) {
+        self.data.iter_mut().for_each(|row| row[i] = row[i] * a);
+    }
+    /// Replace column `i` with the sum of column `i` and `a` times column `j`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` or `j` are out of bounds. That is `i>=N` or `j>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use malg::*;
+    /// let mut a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<2,3,u8>::new([[1,2,5], [4,5,14]]);
+    ///
+    /// a.add_cols(2,0,2);
+    ///
+    /// assert_eq!(a,b)
+    /// ```
+    fn add_cols(&mut self, i: usize, j: usize, a: T) {
+        self.data
+            .iter_mut()
+            .for_each(|row| row[i] = row[i] + row[j] * a);
+    }
+    /// The `i`th column of `self`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` is out of bounds. That is `i>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use malg::*;
+    /// let a = Matrix::<3,2,u8>::new([[1,2], [3,4], [5,6]]);
+    ///
+    /// let col = a.get_col(1);
+    ///
+    /// assert_eq!(col, vec![2, 4, 6])
+    /// ```
+    fn get_col(&self, i: usize) -> Vec<T> {
+        self.data.iter().map(|row| row[i]).collect()
+    }
+}
@@ -0,0 +1,123 @@
+use num_traits::{Float, One, Zero};
+
+use crate::{
+    back_substitution, forward_substitution, Factorization, Matrix, MatrixEntry, SquareMatrix,
+};
+
+/// LU factorization `PA = LU` of an `N`-by-`N` matrix `A` computed by Gaussian elimination with partial pivoting,
+/// where `P` is a permutation matrix, `L` is unit lower triangular and `U` is upper triangular.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Lu<const N: usize, T: MatrixEntry> {
+    lu: SquareMatrix<N, T>,
+    permutation: [usize; N],
+    sign: T,
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Lu<N, T> {
+    /// Factorize the square matrix `a`.
+    ///
+    /// The factorization always exists, but `U` has a zero on its diagonal when `a` is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Lu, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let lu = Lu::new(&a);
+    /// assert_eq!(lu.get_p() * a, lu.get_l() * lu.get_u());
+    /// ```
+    pub fn new(a: &SquareMatrix<N, T>) -> Lu<N, T> {
+        let mut lu = *a;
+        let mut permutation: [usize; N] = std::array::from_fn(|i| i);
+        let mut sign = T::one();
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&i, &j| {
+                    lu.data[i][k]
+                        .abs()
+                        .partial_cmp(&lu.data[j][k].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+            if lu.data[pivot][k].is_zero() {
+                continue;
+            }
+            if pivot != k {
+                lu.data.swap(pivot, k);
+                permutation.swap(pivot, k);
+                sign = -sign;
+            }
+            for i in k + 1..N {
+                let multiplier = lu.data[i][k] / lu.data[k][k];
+                lu.data[i][k] = multiplier;
+                for j in k + 1..N {
+                    lu.data[i][j] = lu.data[i][j] - multiplier * lu.data[k][j];
+                }
+            }
+        }
+        Lu::<N, T> {
+            lu,
+            permutation,
+            sign,
+        }
+    }
+    /// Unit lower triangular factor `L`.
+    pub fn get_l(&self) -> SquareMatrix<N, T> {
+        let mut l = SquareMatrix::<N, T>::one();
+        for i in 0..N {
+            l.data[i][..i].copy_from_slice(&self.lu.data[i][..i]);
+        }
+        l
+    }
+    /// Upper triangular factor `U`.
+    pub fn get_u(&self) -> SquareMatrix<N, T> {
+        let mut u = SquareMatrix::<N, T>::zero();
+        for i in 0..N {
+            u.data[i][i..].copy_from_slice(&self.lu.data[i][i..]);
+        }
+        u
+    }
+    /// Permutation matrix `P`.
+    pub fn get_p(&self) -> SquareMatrix<N, T> {
+        let mut p = SquareMatrix::<N, T>::zero();
+        for (i, &j) in self.permutation.iter().enumerate() {
+            p.data[i][j] = T::one();
+        }
+        p
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Factorization<N> for Lu<N, T> {
+    type Output = T;
+    /// Solve `Ax = b` by forward and back substitution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[5.0], [11.0]]);
+    /// let x = Lu::new(&a).solve_column(&b).unwrap();
+    /// assert_eq!(x, Matrix::<2,1,f64>::new([[1.0], [2.0]]));
+    /// ```
+    fn solve_column(&self, b: &Matrix<N, 1, T>) -> Option<Matrix<N, 1, T>> {
+        let mut permuted = *b;
+        for (i, &j) in self.permutation.iter().enumerate() {
+            permuted.data[i][0] = b.data[j][0];
+        }
+        let y = forward_substitution(&self.lu, &permuted, true)?;
+        back_substitution(&self.lu, &y)
+    }
+    /// The determinant of `A`, the signed product of the diagonal of `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(Lu::new(&a).det(), -2.0);
+    /// ```
+    fn det(&self) -> T {
+        (0..N).fold(self.sign, |det, i| det * self.lu.data[i][i])
+    }
+}
@@ -0,0 +1,92 @@
+use num_traits::Float;
+
+use crate::{dim, Matrix, MatrixEntry, MatrixError};
+
+/// Solve the matrix equation `AXB = C` for `X`.
+///
+/// The equation is vectorized into the linear system `(Bᵀ ⊗ A)vec(X) = vec(C)`, where `⊗` is the Kronecker product
+/// and `vec` stacks the columns of a matrix, and solved by Gaussian elimination with partial pivoting.
+/// This is intended for small systems, as the vectorized system has `(N·P)²` entries.
+/// If the vectorized system is singular, get [`MatrixError::Singular`] instead.
+///
+/// `M·Q` must equal `N·P`, so that the vectorized system is square, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{solve_matrix_equation, Matrix};
+/// let a = Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+/// let b = Matrix::<2,2,f64>::new([[2.0, 0.0], [1.0, 1.0]]);
+/// let c = Matrix::<2,2,f64>::new([[12.0, 2.0], [26.0, 4.0]]);
+///
+/// let x = solve_matrix_equation(&a, &b, &c).unwrap();
+///
+/// let expected = Matrix::<2,2,f64>::new([[1.0, 0.0], [2.0, 1.0]]);
+/// assert!((x - expected).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+/// ```
+pub fn solve_matrix_equation<
+    const M: usize,
+    const N: usize,
+    const P: usize,
+    const Q: usize,
+    T: MatrixEntry + Float,
+>(
+    a: &Matrix<M, N, T>,
+    b: &Matrix<P, Q, T>,
+    c: &Matrix<M, Q, T>,
+) -> Result<Matrix<N, P, T>, MatrixError> {
+    const { dim::assert_product(M * Q, N, P, "the vectorized system must be square") };
+    let size = N * P;
+    let mut system = vec![vec![T::zero(); size + 1]; size];
+    for i in 0..M {
+        for q in 0..Q {
+            let row = &mut system[q * M + i];
+            for j in 0..N {
+                for p in 0..P {
+                    row[p * N + j] = a.data[i][j] * b.data[p][q];
+                }
+            }
+            row[size] = c.data[i][q];
+        }
+    }
+    let solution = solve_dense(system).ok_or(MatrixError::Singular)?;
+    let mut x = Matrix::<N, P, T>::new([[T::zero(); P]; N]);
+    for j in 0..N {
+        for p in 0..P {
+            x.data[j][p] = solution[p * N + j];
+        }
+    }
+    Ok(x)
+}
+
+/// Solve the square system whose augmented rows are `system` by Gaussian elimination with partial pivoting.
+/// If the system is singular, get [`None`] instead.
+pub(crate) fn solve_dense<T: Float>(mut system: Vec<Vec<T>>) -> Option<Vec<T>> {
+    let size = system.len();
+    for k in 0..size {
+        let pivot = (k..size).max_by(|&i, &j| {
+            system[i][k]
+                .abs()
+                .partial_cmp(&system[j][k].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if system[pivot][k].is_zero() {
+            return None;
+        }
+        system.swap(pivot, k);
+        let (upper, lower) = system.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower.iter_mut() {
+            let multiplier = row[k] / pivot_row[k];
+            for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(k) {
+                *entry = *entry - multiplier * *pivot_entry;
+            }
+        }
+    }
+    let mut solution = vec![T::zero(); size];
+    for i in (0..size).rev() {
+        let sum = (i + 1..size).fold(system[i][size], |sum, j| sum - system[i][j] * solution[j]);
+        solution[i] = sum / system[i][i];
+    }
+    Some(solution)
+}
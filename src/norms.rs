@@ -0,0 +1,279 @@
+use num_traits::{Float, One, Zero};
+
+use crate::{AbsValue, Matrix, MatrixEntry, SquareMatrix};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + AbsValue + Zero> Matrix<M, N, T> {
+    /// The max norm of `self`, the largest absolute value of any entry, or zero for an empty matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// assert_eq!(Matrix::<2,2,i64>::new([[1, -7], [3, 4]]).norm_max(), 7);
+    /// assert_eq!(Matrix::<1,2,f64>::new([[-0.5, 0.25]]).norm_max(), 0.5);
+    /// ```
+    pub fn norm_max(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .map(AbsValue::abs_value)
+            .fold(T::zero(), larger)
+    }
+
+    /// The 1-norm of `self`, the operator norm induced by the vector 1-norm, which is the largest sum of the absolute
+    /// values of the entries in any column, or zero for an empty matrix. For integers, sums too large for `T` saturate
+    /// at its largest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,i32>::new([[1, -7, 2], [3, 4, -6]]);
+    /// assert_eq!(a.norm_1(), 11);
+    /// assert_eq!(a.transpose().norm_1(), a.norm_inf());
+    ///
+    /// assert_eq!(Matrix::<2,1,i8>::new([[100], [100]]).norm_1(), i8::MAX);
+    /// ```
+    pub fn norm_1(&self) -> T {
+        let mut column_sums = [T::zero(); N];
+        for row in self.data.iter() {
+            for (sum, entry) in column_sums.iter_mut().zip(row) {
+                *sum = sum.saturating_sum(entry.abs_value());
+            }
+        }
+        column_sums.into_iter().fold(T::zero(), larger)
+    }
+
+    /// The infinity-norm of `self`, the operator norm induced by the vector infinity-norm, which is the largest sum of
+    /// the absolute values of the entries in any row, or zero for an empty matrix. For integers, sums too large for `T`
+    /// saturate at its largest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,f64>::new([[1.0, -7.0, 2.0], [3.0, 4.0, -6.0]]);
+    /// assert_eq!(a.norm_inf(), 13.0);
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        self.data
+            .iter()
+            .map(|row| {
+                row.iter().fold(T::zero(), |sum, entry| {
+                    sum.saturating_sum(entry.abs_value())
+                })
+            })
+            .fold(T::zero(), larger)
+    }
+}
+
+/// The larger of `a` and `b`, or `a` if they are unordered.
+fn larger<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, T> {
+    /// The Frobenius norm of `self`, the square root of the sum of the squares of its entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, -2.0], [2.0, 4.0]]);
+    /// assert_eq!(a.frobenius_norm(), 5.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(T::zero(), |sum, entry| sum + *entry * *entry)
+            .sqrt()
+    }
+
+    /// The Frobenius inner product `⟨A, B⟩ = tr(AᵀB)`, the sum of the products of corresponding entries,
+    /// treating both matrices as vectors of length `MN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,2,f64>::new([[0.5, 0.0], [-1.0, 2.0]]);
+    /// assert_eq!(a.frobenius_inner_product(&b), 5.5);
+    /// assert_eq!(a.frobenius_inner_product(&a), a.frobenius_norm().powi(2));
+    /// ```
+    pub fn frobenius_inner_product(&self, other: &Matrix<M, N, T>) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .fold(T::zero(), |sum, (a, b)| sum + *a * *b)
+    }
+
+    /// The distance `‖A - B‖` between `self` and `other` in the Frobenius norm,
+    /// such as a stopping criterion for successive iterates of an optimization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,2,f64>::new([[1.0, 5.0], [7.0, 4.0]]);
+    /// assert_eq!(a.distance_frobenius(&b), 5.0);
+    /// ```
+    pub fn distance_frobenius(&self, other: &Matrix<M, N, T>) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .fold(T::zero(), |sum, (a, b)| sum + (*a - *b) * (*a - *b))
+            .sqrt()
+    }
+
+    /// The cosine of the angle between `self` and `other` as vectors, `⟨A, B⟩ / (‖A‖‖B‖)`,
+    /// which is one when one is a positive multiple of the other.
+    /// If either matrix is zero, the angle is undefined, so get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let rotation = Matrix::<2,2,f64>::new([[0.0, -1.0], [1.0, 0.0]]);
+    /// let identity = Matrix::<2,2,f64>::new([[1.0, 0.0], [0.0, 1.0]]);
+    /// assert_eq!(rotation.cosine_similarity(&identity), Some(0.0));
+    /// assert_eq!(identity.cosine_similarity(&(identity * 3.0)), Some(1.0));
+    /// assert_eq!(identity.cosine_similarity(&Matrix::new([[0.0; 2]; 2])), None);
+    /// ```
+    pub fn cosine_similarity(&self, other: &Matrix<M, N, T>) -> Option<T> {
+        let norms = self.frobenius_norm() * other.frobenius_norm();
+        if norms.is_zero() {
+            return None;
+        }
+        Some(self.frobenius_inner_product(other) / norms)
+    }
+
+    /// An estimate of the spectral norm (operator 2-norm) of `self`, its largest singular value,
+    /// computed by power iteration on `AᵀA` without a full singular value decomposition.
+    ///
+    /// Iteration starts from the largest row of `self` and stops once successive estimates agree to
+    /// the relative tolerance `tol`, or after 10,000 iterations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,f64>::new([[3.0, 0.0], [4.0, 5.0]]);
+    /// let norm = a.operator_norm_2(1e-12);
+    /// assert!((norm - 45.0f64.sqrt()).abs() < 1e-9);
+    /// ```
+    pub fn operator_norm_2(&self, tol: T) -> T {
+        const MAX_ITERATIONS: usize = 10_000;
+        let largest_row = self.data.iter().max_by(|a, b| {
+            let a_norm = a.iter().fold(T::zero(), |sum, e| sum + *e * *e);
+            let b_norm = b.iter().fold(T::zero(), |sum, e| sum + *e * *e);
+            a_norm
+                .partial_cmp(&b_norm)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let Some(largest_row) = largest_row else {
+            return T::zero();
+        };
+        let mut v = Matrix::<N, 1, T>::new(largest_row.map(|entry| [entry]));
+        let mut estimate = T::zero();
+        for _ in 0..MAX_ITERATIONS {
+            let v_norm = v.frobenius_norm();
+            if v_norm.is_zero() {
+                return T::zero();
+            }
+            v = v * (T::one() / v_norm);
+            let av = *self * v;
+            let next_estimate = av.frobenius_norm();
+            let converged = (next_estimate - estimate).abs() <= tol * next_estimate;
+            estimate = next_estimate;
+            if converged {
+                break;
+            }
+            v = self.transpose() * av;
+        }
+        estimate
+    }
+
+    /// The spectral norm (operator 2-norm) of `self`, its largest singular value `σ₁`, from a full singular value
+    /// decomposition by one-sided Jacobi rotations. When only an estimate is needed, [`Matrix::operator_norm_2`] finds
+    /// it by power iteration at a lower cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,f64>::new([[3.0, 0.0, 0.0], [0.0, 0.0, -4.0]]);
+    /// assert!((a.spectral_norm() - 4.0).abs() < 1e-12);
+    /// assert!((a.spectral_norm() - a.operator_norm_2(1e-12)).abs() < 1e-9);
+    /// ```
+    pub fn spectral_norm(&self) -> T {
+        self.singular_values()
+            .into_iter()
+            .fold(T::zero(), |largest, sigma| largest.max(sigma))
+    }
+
+    /// The nuclear norm (trace norm) of `self`, the sum `σ₁ + σ₂ + ⋯` of its singular values,
+    /// from a full singular value decomposition by one-sided Jacobi rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,f64>::new([[3.0, 0.0, 0.0], [0.0, 0.0, -4.0]]);
+    /// assert!((a.nuclear_norm() - 7.0).abs() < 1e-12);
+    ///
+    /// // A rank one matrix has a single nonzero singular value.
+    /// let rank_one = Matrix::<2,2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!((rank_one.nuclear_norm() - rank_one.frobenius_norm()).abs() < 1e-12);
+    /// ```
+    pub fn nuclear_norm(&self) -> T {
+        self.singular_values()
+            .into_iter()
+            .fold(T::zero(), |sum, sigma| sum + sigma)
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The Frobenius norm of `self - I`, the distance of `self` from the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 0.0], [-0.3, 1.4]]);
+    /// assert!((a.distance_from_identity() - 0.5).abs() < 1e-15);
+    /// ```
+    pub fn distance_from_identity(&self) -> T {
+        (*self - SquareMatrix::one()).frobenius_norm()
+    }
+
+    /// Whether every entry of `self` is within `eps` of the corresponding entry of the identity matrix,
+    /// such as when checking that a computed product `AA⁻¹` or `QᵀQ` is the identity up to rounding errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, SquareMatrix};
+    /// let a = SquareMatrix::<3,f64>::new([[4.0, 1.0, 2.0], [0.5, 3.0, 1.0], [1.0, 1.0, 5.0]]);
+    /// let a_inv = Lu::new(&a).inverse().unwrap();
+    ///
+    /// assert!((a * a_inv).is_identity_with_tol(1e-12));
+    /// assert!(!a.is_identity_with_tol(1e-12));
+    /// ```
+    pub fn is_identity_with_tol(&self, eps: T) -> bool {
+        self.data.iter().enumerate().all(|(i, row)| {
+            row.iter().enumerate().all(|(j, entry)| {
+                let identity = if i == j { T::one() } else { T::zero() };
+                (*entry - identity).abs() <= eps
+            })
+        })
+    }
+}
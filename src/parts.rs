@@ -0,0 +1,138 @@
+use num_traits::{Float, Zero};
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Zero> Matrix<M, N, T> {
+    /// The upper triangular part of `self`, including the diagonal, with all other entries set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a.upper_triangular_part(), Matrix::new([[1, 2, 3], [0, 5, 6], [0, 0, 9]]));
+    /// ```
+    pub fn upper_triangular_part(&self) -> Self {
+        self.masked(|i, j| i <= j)
+    }
+
+    /// The lower triangular part of `self`, including the diagonal, with all other entries set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a.lower_triangular_part(), Matrix::new([[1, 0, 0], [4, 5, 0], [7, 8, 9]]));
+    /// ```
+    pub fn lower_triangular_part(&self) -> Self {
+        self.masked(|i, j| i >= j)
+    }
+
+    /// The strictly upper triangular part of `self`, excluding the diagonal, with all other entries set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a.strict_upper(), Matrix::new([[0, 2, 3], [0, 0, 6], [0, 0, 0]]));
+    /// ```
+    pub fn strict_upper(&self) -> Self {
+        self.masked(|i, j| i < j)
+    }
+
+    /// The strictly lower triangular part of `self`, excluding the diagonal, with all other entries set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,i32>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a.strict_lower(), Matrix::new([[0, 0, 0], [4, 0, 0], [7, 8, 0]]));
+    /// ```
+    pub fn strict_lower(&self) -> Self {
+        self.masked(|i, j| i > j)
+    }
+
+    /// The diagonal part of `self`, with all off-diagonal entries set to zero.
+    ///
+    /// # Examples
+    ///
+    /// The strict lower, diagonal and strict upper parts sum back to the original matrix,
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,i32>::new([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.diagonal_matrix(), Matrix::new([[1, 0, 0], [0, 5, 0]]));
+    /// assert_eq!(a.strict_lower() + a.diagonal_matrix() + a.strict_upper(), a);
+    /// ```
+    pub fn diagonal_matrix(&self) -> Self {
+        self.masked(|i, j| i == j)
+    }
+
+    /// A copy of `self` keeping only the entries at indices `(i, j)` where `keep(i, j)` holds.
+    fn masked(&self, keep: impl Fn(usize, usize) -> bool) -> Self {
+        let mut part = *self;
+        for (i, row) in part.data.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                if !keep(i, j) {
+                    *entry = T::zero();
+                }
+            }
+        }
+        part
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The symmetric part `(A + Aᵀ)/2` of a square matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 4.0], [2.0, 3.0]]);
+    /// assert_eq!(a.symmetric_part(), SquareMatrix::new([[1.0, 3.0], [3.0, 3.0]]));
+    /// assert_eq!(a.symmetric_part() + a.skew_part(), a);
+    /// ```
+    pub fn symmetric_part(&self) -> Self {
+        (*self + self.transpose()) * (T::one() + T::one()).recip()
+    }
+
+    /// The skew-symmetric part `(A - Aᵀ)/2` of a square matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 4.0], [2.0, 3.0]]);
+    /// assert_eq!(a.skew_part(), SquareMatrix::new([[0.0, 1.0], [-1.0, 0.0]]));
+    /// ```
+    pub fn skew_part(&self) -> Self {
+        (*self - self.transpose()) * (T::one() + T::one()).recip()
+    }
+
+    /// Replace `self` with its [symmetric part](SquareMatrix::symmetric_part) in place,
+    /// such as to remove rounding errors that break the symmetry of a matrix before passing it to a symmetric-only algorithm.
+    /// The result is exactly symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let mut a = SquareMatrix::<2,f64>::new([[2.0, 1.0 + 1e-15], [1.0, 3.0]]);
+    /// a.symmetrize_in_place();
+    /// assert_eq!(a, a.transpose());
+    /// ```
+    pub fn symmetrize_in_place(&mut self) {
+        let half = (T::one() + T::one()).recip();
+        for i in 0..N {
+            for j in 0..i {
+                let mean = (self.data[i][j] + self.data[j][i]) * half;
+                self.data[i][j] = mean;
+                self.data[j][i] = mean;
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use num_traits::{CheckedAdd, Zero};
+use std::ops::Add;
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+/// Combine the optional path weights `a` and `b` with `pick`, where [`None`] is the identity, an absent path.
+fn combine<T: Copy>(a: Option<T>, b: Option<T>, pick: impl Fn(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(pick(a, b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// The smaller of `a` and `b`.
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The larger of `a` and `b`.
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Add<Output = T> + PartialOrd>
+    Matrix<M, N, Option<T>>
+{
+    /// The product of `self` and `other` over the min-plus semiring, `(AB)ᵢⱼ = minₖ (Aᵢₖ + Bₖⱼ)`,
+    /// where [`None`] is an infinite weight.
+    ///
+    /// If `self` and `other` hold the weights of edges between vertices, the product holds the weights of the
+    /// shortest paths taking one edge from each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,Option<i32>>::new([[Some(0), Some(4)], [None, Some(0)]]);
+    /// let b = Matrix::<2,2,Option<i32>>::new([[Some(1), None], [Some(2), Some(3)]]);
+    /// assert_eq!(a.min_plus_mul(&b), Matrix::new([[Some(1), Some(7)], [Some(2), Some(3)]]));
+    /// ```
+    pub fn min_plus_mul<const P: usize>(
+        &self,
+        other: &Matrix<N, P, Option<T>>,
+    ) -> Matrix<M, P, Option<T>> {
+        self.semiring_mul(other, min)
+    }
+
+    /// The product of `self` and `other` over the max-plus semiring, `(AB)ᵢⱼ = maxₖ (Aᵢₖ + Bₖⱼ)`,
+    /// where [`None`] is a weight of negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,Option<i32>>::new([[Some(0), Some(4)], [None, Some(0)]]);
+    /// let b = Matrix::<2,2,Option<i32>>::new([[Some(1), None], [Some(2), Some(3)]]);
+    /// assert_eq!(a.max_plus_mul(&b), Matrix::new([[Some(6), Some(7)], [Some(2), Some(3)]]));
+    /// ```
+    pub fn max_plus_mul<const P: usize>(
+        &self,
+        other: &Matrix<N, P, Option<T>>,
+    ) -> Matrix<M, P, Option<T>> {
+        self.semiring_mul(other, max)
+    }
+
+    /// The product of `self` and `other` where entries are added with `pick` and multiplied with `+`.
+    fn semiring_mul<const P: usize>(
+        &self,
+        other: &Matrix<N, P, Option<T>>,
+        pick: impl Fn(T, T) -> T + Copy,
+    ) -> Matrix<M, P, Option<T>> {
+        let mut product = [[None; P]; M];
+        for (i, row) in product.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                for k in 0..N {
+                    let path = self.data[i][k].zip(other.data[k][j]).map(|(a, b)| a + b);
+                    *entry = combine(*entry, path, pick);
+                }
+            }
+        }
+        Matrix::new(product)
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + CheckedAdd + PartialOrd + Zero> SquareMatrix<N, Option<T>> {
+    /// The weights of the shortest paths between every pair of vertices of the graph with edge weights `self`,
+    /// where entry `(i, j)` is the weight of the edge from `i` to `j`, or [`None`] if there is no edge.
+    ///
+    /// The paths are found with the Floyd–Warshall algorithm in `O(N³)` time. Entry `(i, j)` of the result is
+    /// [`None`] if there is no path from `i` to `j`, and every vertex reaches itself with weight zero.
+    /// If the graph has a cycle of negative weight, shortest paths are not defined and [`None`] is returned.
+    /// [`None`] is also returned if a path weight overflows `T`, which a negative cycle may cause before it is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let weights = SquareMatrix::<3,Option<i32>>::new([
+    ///     [None, Some(4), Some(9)],
+    ///     [None, None, Some(2)],
+    ///     [Some(-1), None, None],
+    /// ]);
+    /// let expected = SquareMatrix::new([
+    ///     [Some(0), Some(4), Some(6)],
+    ///     [Some(1), Some(0), Some(2)],
+    ///     [Some(-1), Some(3), Some(0)],
+    /// ]);
+    /// assert_eq!(weights.shortest_paths(), Some(expected));
+    ///
+    /// let negative_cycle = SquareMatrix::<2,Option<i32>>::new([[None, Some(1)], [Some(-2), None]]);
+    /// assert_eq!(negative_cycle.shortest_paths(), None);
+    /// ```
+    pub fn shortest_paths(&self) -> Option<Self> {
+        let mut distance = self.data;
+        for (i, row) in distance.iter_mut().enumerate() {
+            row[i] = combine(row[i], Some(T::zero()), min);
+        }
+        for k in 0..N {
+            for i in 0..N {
+                for j in 0..N {
+                    let path = match distance[i][k].zip(distance[k][j]) {
+                        Some((a, b)) => Some(a.checked_add(&b)?),
+                        None => None,
+                    };
+                    distance[i][j] = combine(distance[i][j], path, min);
+                }
+            }
+            if (0..N).any(|i| distance[i][i].is_some_and(|d| d < T::zero())) {
+                return None;
+            }
+        }
+        Some(Matrix::new(distance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check a negative cycle whose path weights overflow a narrow integer type gives no shortest paths
+    #[test]
+    fn check_shortest_paths_overflow() -> Result<(), Box<dyn Error>> {
+        let weights = SquareMatrix::<2, Option<i8>>::new([[None, Some(-100)], [Some(-100), None]]);
+        assert_eq!(weights.shortest_paths(), None);
+
+        let heavy = SquareMatrix::<3, Option<i8>>::new([
+            [None, Some(100), None],
+            [None, None, Some(100)],
+            [None, None, None],
+        ]);
+        assert_eq!(heavy.shortest_paths(), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use num_traits::Float;
+
+use crate::{Factorization, Lu, MatrixEntry, MatrixError, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The polar decomposition `A = UP` of a nonsingular matrix, with `U` orthogonal and `P` symmetric positive definite,
+    /// such as the rotation and stretch of a deformation gradient.
+    ///
+    /// `U` is found by the scaled Newton iteration `X ← (γX + X⁻ᵀ/γ)/2`, with `γ = √(‖X⁻¹‖/‖X‖)` in the Frobenius norm,
+    /// which converges quadratically, so it takes one more step once successive iterates agree to `√ε`.
+    /// `P = UᵀA` is then symmetrized.
+    /// If `self` is singular, get [`MatrixError::Singular`], and if the iteration does not converge within 100 iterations,
+    /// get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let rotation = SquareMatrix::<2,f64>::new([[0.0, -1.0], [1.0, 0.0]]);
+    /// let stretch = SquareMatrix::<2,f64>::new([[2.0, 1.0], [1.0, 3.0]]);
+    /// let deformation = rotation * stretch;
+    ///
+    /// let (u, p) = deformation.polar().unwrap();
+    ///
+    /// assert!(u.distance_frobenius(&rotation) < 1e-12);
+    /// assert!(p.distance_frobenius(&stretch) < 1e-12);
+    /// assert!((u.transpose() * u).is_identity_with_tol(1e-12));
+    /// ```
+    ///
+    /// ```
+    /// # use malg::{MatrixError, SquareMatrix};
+    /// let singular = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.polar(), Err(MatrixError::Singular));
+    /// ```
+    pub fn polar(&self) -> Result<(SquareMatrix<N, T>, SquareMatrix<N, T>), MatrixError> {
+        const MAX_ITERATIONS: usize = 100;
+        let two = T::one() + T::one();
+        let tol = T::epsilon().sqrt();
+        let step = |u: &SquareMatrix<N, T>| {
+            let inverse = Lu::new(u).inverse().ok_or(MatrixError::Singular)?;
+            let scale = (inverse.frobenius_norm() / u.frobenius_norm()).sqrt();
+            Ok((*u * scale + inverse.transpose() * scale.recip()) * two.recip())
+        };
+        let mut u = *self;
+        for _ in 0..MAX_ITERATIONS {
+            let next = step(&u)?;
+            let converged = next.distance_frobenius(&u) <= tol * next.frobenius_norm();
+            u = next;
+            if converged {
+                u = step(&u)?;
+                let p = u.transpose() * *self;
+                return Ok((u, (p + p.transpose()) * two.recip()));
+            }
+        }
+        Err(MatrixError::NotConverged)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{Factorization, Lu, Matrix, SquareMatrix};
+
+/// Floating point precision a computation was carried out in.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Precision {
+    /// 32-bit `f32` arithmetic.
+    Single,
+    /// 64-bit `f64` arithmetic.
+    Double,
+}
+
+impl<const N: usize> SquareMatrix<N, f32> {
+    /// Solve `AX = B` in `f32`, falling back to `f64` when the single precision solution cannot be trusted,
+    /// and report which [`Precision`] the returned solution was computed in.
+    ///
+    /// The single precision solution is rejected if `A` is singular in `f32`, if its
+    /// [relative backward error](Matrix::relative_backward_error), computed in `f64`, exceeds `N` units of roundoff,
+    /// or if the condition number estimate `‖A‖‖A⁻¹‖` in the Frobenius norm exceeds `1/√ε`, so that the forward error
+    /// could exceed `√ε`. The system is then cast to `f64`, solved again, and the solution rounded back to `f32`.
+    /// If `A` is singular in `f64` as well, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, Precision, SquareMatrix};
+    /// let well_conditioned = SquareMatrix::<2,f32>::new([[2.0, 1.0], [1.0, 3.0]]);
+    /// let b = Matrix::<2,1,f32>::new([[3.0], [4.0]]);
+    /// let (x, precision) = well_conditioned.solve_adaptive(&b).unwrap();
+    /// assert_eq!(precision, Precision::Single);
+    /// assert_eq!(x, Matrix::new([[1.0], [1.0]]));
+    ///
+    /// let delta = 2f32.powi(-12);
+    /// let ill_conditioned = SquareMatrix::<2,f32>::new([[1.0, 1.0], [1.0, 1.0 + delta]]);
+    /// let b = Matrix::<2,1,f32>::new([[2.0], [2.0 + delta]]);
+    /// let (x, precision) = ill_conditioned.solve_adaptive(&b).unwrap();
+    /// assert_eq!(precision, Precision::Double);
+    /// assert_eq!(x, Matrix::new([[1.0], [1.0]]));
+    /// ```
+    pub fn solve_adaptive<const P: usize>(
+        &self,
+        b: &Matrix<N, P, f32>,
+    ) -> Option<(Matrix<N, P, f32>, Precision)> {
+        let lu = Lu::new(self);
+        if let (Some(x), Some(inverse)) = (lu.solve(b), lu.inverse()) {
+            let backward_error = to_f64(self).relative_backward_error(&to_f64(b), &to_f64(&x));
+            let condition = f64::from(self.frobenius_norm()) * f64::from(inverse.frobenius_norm());
+            let roundoff = f64::from(f32::EPSILON);
+            if backward_error <= N as f64 * roundoff && condition <= roundoff.sqrt().recip() {
+                return Some((x, Precision::Single));
+            }
+        }
+        let x = Lu::new(&to_f64(self)).solve(&to_f64(b))?;
+        Some((
+            Matrix::new(x.data.map(|row| row.map(|entry| entry as f32))),
+            Precision::Double,
+        ))
+    }
+}
+
+/// Widen each entry of `a` to `f64`.
+fn to_f64<const M: usize, const N: usize>(a: &Matrix<M, N, f32>) -> Matrix<M, N, f64> {
+    Matrix::new(a.data.map(|row| row.map(f64::from)))
+}
@@ -0,0 +1,40 @@
+use std::ops::ControlFlow;
+
+/// Progress of a long-running reduction or iterative solver, reported to an observer after each step.
+///
+/// Observers return [`ControlFlow::Continue`] to carry on, or [`ControlFlow::Break`] to cancel the computation,
+/// which then returns [`MatrixError::Cancelled`](crate::MatrixError::Cancelled).
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Progress<T> {
+    step: usize,
+    total: usize,
+    residual: Option<T>,
+}
+
+impl<T: Copy> Progress<T> {
+    pub(crate) fn new(step: usize, total: usize, residual: Option<T>) -> Self {
+        Progress {
+            step,
+            total,
+            residual,
+        }
+    }
+    /// Number of steps completed, starting from one.
+    pub fn get_step(&self) -> usize {
+        self.step
+    }
+    /// Number of steps in the whole computation, or the iteration limit of an iterative solver.
+    pub fn get_total(&self) -> usize {
+        self.total
+    }
+    /// Measure of the remaining error of an iterative solver, which it stops once it is within tolerance.
+    /// Direct methods, such as row reduction, report [`None`].
+    pub fn get_residual(&self) -> Option<T> {
+        self.residual
+    }
+}
+
+/// An observer ignoring progress, for running observed computations to completion.
+pub(crate) fn ignore_progress<T>(_: &Progress<T>) -> ControlFlow<()> {
+    ControlFlow::Continue(())
+}
@@ -0,0 +1,111 @@
+use num_traits::{Float, One};
+
+use crate::{back_substitution, Factorization, Matrix, MatrixEntry};
+
+/// QR factorization `A = QR` of an `M`-by-`N` matrix `A` computed with Householder reflections,
+/// where `Q` is an `M`-by-`M` orthogonal matrix and `R` is `M`-by-`N` upper triangular.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Qr<const M: usize, const N: usize, T: MatrixEntry> {
+    q: Matrix<M, M, T>,
+    r: Matrix<M, N, T>,
+    sign: T,
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Qr<M, N, T> {
+    /// Factorize the matrix `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, Qr};
+    /// let a = Matrix::<3,2,f64>::new([[3.0, 0.0], [4.0, 1.0], [0.0, 2.0]]);
+    /// let qr = Qr::new(&a);
+    /// let r = qr.get_r();
+    /// assert!(r.get_entry(1,0).unwrap().abs() < 1e-12 && r.get_entry(2,1).unwrap().abs() < 1e-12);
+    /// assert!((*qr.get_q() * *r - a).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn new(a: &Matrix<M, N, T>) -> Qr<M, N, T> {
+        let mut q = Matrix::<M, M, T>::one();
+        let mut r = *a;
+        let mut sign = T::one();
+        for k in 0..N.min(M.saturating_sub(1)) {
+            let norm = (k..M)
+                .fold(T::zero(), |acc, i| acc + r.data[i][k] * r.data[i][k])
+                .sqrt();
+            if norm.is_zero() {
+                continue;
+            }
+            let alpha = if r.data[k][k] > T::zero() {
+                -norm
+            } else {
+                norm
+            };
+            let mut v = [T::zero(); M];
+            for (v_i, row) in v.iter_mut().zip(r.data.iter()).skip(k) {
+                *v_i = row[k];
+            }
+            v[k] = v[k] - alpha;
+            let v_norm_squared = (k..M).fold(T::zero(), |acc, i| acc + v[i] * v[i]);
+            if v_norm_squared.is_zero() {
+                continue;
+            }
+            let two = T::one() + T::one();
+            // Apply H = I - 2vvᵀ/(vᵀv) on the left of R and on the right of Q.
+            for j in 0..N {
+                let dot = (k..M).fold(T::zero(), |acc, i| acc + v[i] * r.data[i][j]);
+                let factor = two * dot / v_norm_squared;
+                for (row, v_i) in r.data.iter_mut().zip(v).skip(k) {
+                    row[j] = row[j] - factor * v_i;
+                }
+            }
+            for row in q.data.iter_mut() {
+                let dot = (k..M).fold(T::zero(), |acc, i| acc + row[i] * v[i]);
+                let factor = two * dot / v_norm_squared;
+                for i in k..M {
+                    row[i] = row[i] - factor * v[i];
+                }
+            }
+            sign = -sign;
+        }
+        Qr::<M, N, T> { q, r, sign }
+    }
+    /// Orthogonal factor `Q`.
+    pub fn get_q(&self) -> &Matrix<M, M, T> {
+        &self.q
+    }
+    /// Upper triangular factor `R`.
+    pub fn get_r(&self) -> &Matrix<M, N, T> {
+        &self.r
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Factorization<N> for Qr<N, N, T> {
+    type Output = T;
+    /// Solve `Ax = b` by back substitution of `Rx = Qᵀb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Matrix, Qr, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[3.0, 0.0], [4.0, 5.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[3.0], [9.0]]);
+    /// let x = Qr::new(&a).solve_column(&b).unwrap();
+    /// assert!((*x.get_entry(0,0).unwrap() - 1.0).abs() < 1e-12);
+    /// assert!((*x.get_entry(1,0).unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    fn solve_column(&self, b: &Matrix<N, 1, T>) -> Option<Matrix<N, 1, T>> {
+        back_substitution(&self.r, &(self.q.transpose() * *b))
+    }
+    /// The determinant of `A`, the product of the diagonal of `R` signed by the number of reflections in `Q`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Qr, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[3.0, 0.0], [4.0, 5.0]]);
+    /// assert!((Qr::new(&a).det() - 15.0).abs() < 1e-12);
+    /// ```
+    fn det(&self) -> T {
+        (0..N).fold(self.sign, |det, i| det * self.r.data[i][i])
+    }
+}
@@ -0,0 +1,228 @@
+use num_traits::{One, Zero};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{dim, Matrix, MatrixEntry, SquareMatrix};
+
+/// Quaternion `w + xi + yj + zk` with parts of type `T`.
+///
+/// Quaternion multiplication is not commutative, so quaternions are not a [`CommutativeRing`](crate::CommutativeRing).
+/// Matrices of quaternions support addition, subtraction, multiplication and transposition, but not row reduction.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{Matrix, Quaternion};
+/// let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+/// let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+/// assert_eq!(i * j, -(j * i));
+///
+/// let a = Matrix::<1,2,Quaternion<f64>>::new([[i, j]]);
+/// assert_eq!(a * a.transpose(), Matrix::new([[Quaternion::new(-2.0, 0.0, 0.0, 0.0)]]));
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub struct Quaternion<T> {
+    w: T,
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: MatrixEntry> Quaternion<T> {
+    /// A new [`Quaternion`] `w + xi + yj + zk`.
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+    /// The real part `w`.
+    pub fn get_real(&self) -> T {
+        self.w
+    }
+    /// The imaginary parts `[x, y, z]`.
+    pub fn get_imaginary(&self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl<T: MatrixEntry + Neg<Output = T>> Quaternion<T> {
+    /// The conjugate `w - xi - yj - zk`.
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// The 4×4 real matrix `L(q)` of left multiplication by `self`, so that `L(p)L(q) = L(pq)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, Quaternion};
+    /// let p = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let q = Quaternion::new(-2.0, 0.5, 1.0, 3.0);
+    /// assert_eq!(p.to_real_matrix() * q.to_real_matrix(), (p * q).to_real_matrix());
+    ///
+    /// let q_column = Matrix::<4,1,f64>::new([[-2.0], [0.5], [1.0], [3.0]]);
+    /// assert_eq!(p.to_real_matrix() * q_column, Matrix::new([[-18.0], [1.5], [-9.0], [-4.5]]));
+    /// ```
+    pub fn to_real_matrix(&self) -> SquareMatrix<4, T> {
+        let Quaternion { w, x, y, z } = *self;
+        SquareMatrix::new([[w, -x, -y, -z], [x, w, -z, y], [y, z, w, -x], [z, -y, x, w]])
+    }
+
+    /// The quaternion whose left multiplication matrix is `matrix`, or `None` if `matrix`
+    /// is not of the form returned by [`Quaternion::to_real_matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Quaternion, SquareMatrix};
+    /// let q = Quaternion::new(1, -2, 3, 5);
+    /// assert_eq!(Quaternion::from_real_matrix(&q.to_real_matrix()), Some(q));
+    /// assert_eq!(Quaternion::from_real_matrix(&SquareMatrix::new([[1; 4]; 4])), None);
+    /// ```
+    pub fn from_real_matrix(matrix: &SquareMatrix<4, T>) -> Option<Self> {
+        let [[w], [x], [y], [z]] = matrix.submatrix::<4, 1>(0, 0).data;
+        let quaternion = Quaternion::new(w, x, y, z);
+        (quaternion.to_real_matrix() == *matrix).then_some(quaternion)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Quaternion<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Quaternion {
+            w: self.w + rhs.w,
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Quaternion<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Quaternion {
+            w: self.w - rhs.w,
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Quaternion<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Quaternion {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Quaternion<T> {
+    type Output = Self;
+    /// The Hamilton product of `self` and `rhs`.
+    fn mul(self, rhs: Self) -> Self {
+        let Quaternion { w, x, y, z } = self;
+        Quaternion {
+            w: w * rhs.w - x * rhs.x - y * rhs.y - z * rhs.z,
+            x: w * rhs.x + x * rhs.w + y * rhs.z - z * rhs.y,
+            y: w * rhs.y - x * rhs.z + y * rhs.w + z * rhs.x,
+            z: w * rhs.z + x * rhs.y - y * rhs.x + z * rhs.w,
+        }
+    }
+}
+
+impl<T: Zero> Zero for Quaternion<T> {
+    fn zero() -> Self {
+        Quaternion {
+            w: T::zero(),
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+        }
+    }
+    fn is_zero(&self) -> bool {
+        self.w.is_zero() && self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+}
+
+impl<T: Copy + Zero + One + Sub<Output = T>> One for Quaternion<T> {
+    fn one() -> Self {
+        Quaternion {
+            w: T::one(),
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+        }
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Neg<Output = T>> Matrix<M, N, Quaternion<T>> {
+    /// The conjugate transpose of a matrix of quaternions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, Quaternion};
+    /// let a = Matrix::<1,2,Quaternion<i32>>::new([[Quaternion::new(1, 2, 3, 4), Quaternion::new(0, 0, 0, 1)]]);
+    /// let a_h = Matrix::new([[Quaternion::new(1, -2, -3, -4)], [Quaternion::new(0, 0, 0, -1)]]);
+    /// assert_eq!(a.conjugate_transpose(), a_h);
+    /// ```
+    pub fn conjugate_transpose(&self) -> Matrix<N, M, Quaternion<T>> {
+        let mut conjugated = self.transpose();
+        for entry in conjugated.data.iter_mut().flatten() {
+            *entry = entry.conjugate();
+        }
+        conjugated
+    }
+
+    /// The `P`-by-`Q` real matrix replacing each quaternion entry with its 4×4 [left multiplication matrix](Quaternion::to_real_matrix),
+    /// so that products of quaternion matrices correspond to products of their real representations.
+    ///
+    /// `P` must be `4M` and `Q` must be `4N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, Quaternion};
+    /// let a = Matrix::<1,2,Quaternion<i32>>::new([[Quaternion::new(1, 2, 3, 4), Quaternion::new(0, 1, 0, -1)]]);
+    /// let b = Matrix::<2,1,Quaternion<i32>>::new([[Quaternion::new(2, 0, 1, 0)], [Quaternion::new(1, 1, 1, 1)]]);
+    ///
+    /// let product = (a * b).to_real_representation::<4, 4>();
+    /// assert_eq!(a.to_real_representation::<4, 8>() * b.to_real_representation::<8, 4>(), product);
+    /// assert_eq!(Matrix::from_real_representation(&product), Some(a * b));
+    /// ```
+    pub fn to_real_representation<const P: usize, const Q: usize>(&self) -> Matrix<P, Q, T> {
+        const { dim::assert_product(P, 4, M, "the real representation must be 4M-by-4N") };
+        const { dim::assert_product(Q, 4, N, "the real representation must be 4M-by-4N") };
+        let mut real = [[T::default(); Q]; P];
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                for (k, block_row) in entry.to_real_matrix().data.iter().enumerate() {
+                    real[4 * i + k][4 * j..4 * j + 4].copy_from_slice(block_row);
+                }
+            }
+        }
+        Matrix::new(real)
+    }
+
+    /// The matrix of quaternions whose [real representation](Matrix::to_real_representation) is `real`,
+    /// or `None` if some 4×4 block of `real` is not a left multiplication matrix.
+    ///
+    /// `P` must be `4M` and `Q` must be `4N`.
+    pub fn from_real_representation<const P: usize, const Q: usize>(
+        real: &Matrix<P, Q, T>,
+    ) -> Option<Self> {
+        const { dim::assert_product(P, 4, M, "the real representation must be 4M-by-4N") };
+        const { dim::assert_product(Q, 4, N, "the real representation must be 4M-by-4N") };
+        let mut data = [[Quaternion::default(); N]; M];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = Quaternion::from_real_matrix(&real.submatrix::<4, 4>(4 * i, 4 * j))?;
+            }
+        }
+        Some(Matrix::new(data))
+    }
+}
@@ -0,0 +1,169 @@
+use num_traits::{Float, Zero};
+use rand::{distributions::uniform::SampleUniform, Rng};
+
+use crate::{Matrix, MatrixEntry, Qr, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + Float + SampleUniform> SquareMatrix<N, T> {
+    /// A random orthogonal matrix, the `Q` factor of a matrix with entries drawn uniformly from `[-1, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use rand::SeedableRng;
+    /// # use malg::SquareMatrix;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let q = SquareMatrix::<3,f64>::random_orthogonal(&mut rng);
+    /// let q_t_q = q.transpose() * q;
+    /// assert!((q_t_q - SquareMatrix::one()).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn random_orthogonal<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        *Qr::new(&Self::random_uniform(rng)).get_q()
+    }
+
+    /// A random symmetric matrix `QΛQᵀ` with eigenvalues `eigenvalues`, where `Q` is a random orthogonal matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use malg::{Factorization, Lu, SquareMatrix};
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let a = SquareMatrix::<3,f64>::random_with_eigenvalues(&mut rng, &[1.0, 2.0, 3.0]);
+    /// assert!((Lu::new(&a).det() - 6.0).abs() < 1e-12);
+    /// assert!((a - a.transpose()).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn random_with_eigenvalues<R: Rng + ?Sized>(rng: &mut R, eigenvalues: &[T; N]) -> Self {
+        let q = Self::random_orthogonal(rng);
+        let mut q_lambda = q;
+        for row in q_lambda.data.iter_mut() {
+            for (entry, eigenvalue) in row.iter_mut().zip(eigenvalues) {
+                *entry = *entry * *eigenvalue;
+            }
+        }
+        q_lambda * q.transpose()
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float + SampleUniform> Matrix<M, N, T> {
+    /// A random matrix with entries drawn uniformly from `[-1, 1]`.
+    pub fn random_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut a = Self::zero();
+        for entry in a.data.iter_mut().flatten() {
+            *entry = rng.gen_range(-T::one()..=T::one());
+        }
+        a
+    }
+
+    /// Add independent noise drawn uniformly from `[-epsilon, epsilon]` to each entry of `self` in place,
+    /// such as to break exact degeneracies before elimination or to test how robust an algorithm is to small errors.
+    /// A negative `epsilon` is taken by its magnitude.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `epsilon` is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use malg::{Factorization, Lu, SquareMatrix};
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let singular = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    ///
+    /// let mut a = singular;
+    /// a.perturb(&mut rng, 1e-6);
+    ///
+    /// assert!((a - singular).as_slice().iter().flatten().all(|e| e.abs() <= 1e-6));
+    /// assert_ne!(Lu::new(&a).det(), 0.0);
+    /// ```
+    pub fn perturb<R: Rng + ?Sized>(&mut self, rng: &mut R, epsilon: T) {
+        assert!(epsilon.is_finite(), "perturbation size must be finite");
+        let epsilon = epsilon.abs();
+        for entry in self.data.iter_mut().flatten() {
+            *entry = *entry + rng.gen_range(-epsilon..=epsilon);
+        }
+    }
+
+    /// A random matrix `UΣVᵀ` with singular values `singular_values`, where `U` and `V` are random orthogonal matrices.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if there are more singular values than the smaller of `M` and `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use malg::{Factorization, Lu, Matrix};
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let a = Matrix::<2,2,f64>::random_with_singular_values(&mut rng, &[4.0, 0.5]);
+    /// assert!((Lu::new(&a).det().abs() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn random_with_singular_values<R: Rng + ?Sized>(
+        rng: &mut R,
+        singular_values: &[T],
+    ) -> Self {
+        assert!(
+            singular_values.len() <= M.min(N),
+            "a {M}-by-{N} matrix has at most {} singular values",
+            M.min(N)
+        );
+        let u = SquareMatrix::<M, T>::random_orthogonal(rng);
+        let v = SquareMatrix::<N, T>::random_orthogonal(rng);
+        let mut sigma = Self::zero();
+        for (i, singular_value) in singular_values.iter().enumerate() {
+            sigma.data[i][i] = *singular_value;
+        }
+        u * sigma * v.transpose()
+    }
+
+    /// A random matrix whose 2-norm condition number is `condition_number`,
+    /// with singular values spaced geometrically between `1` and `1/condition_number`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use malg::{Factorization, Lu, Matrix};
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let a = Matrix::<3,3,f64>::random_with_condition_number(&mut rng, 100.0);
+    /// // The singular values are 1, 0.1 and 0.01.
+    /// assert!((Lu::new(&a).det().abs() - 1e-3).abs() < 1e-12);
+    /// ```
+    pub fn random_with_condition_number<R: Rng + ?Sized>(rng: &mut R, condition_number: T) -> Self {
+        let count = M.min(N);
+        let singular_values: Vec<T> = (0..count)
+            .map(|i| {
+                if count == 1 {
+                    T::one()
+                } else {
+                    let fraction = T::from(i).unwrap() / T::from(count - 1).unwrap();
+                    condition_number.powf(-fraction)
+                }
+            })
+            .collect();
+        Self::random_with_singular_values(rng, &singular_values)
+    }
+
+    /// A random matrix of rank `rank`, with nonzero singular values drawn uniformly from `[1, 2]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rank` exceeds the smaller of `M` and `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rand::SeedableRng;
+    /// # use malg::{Factorization, Lu, Matrix};
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let a = Matrix::<3,3,f64>::random_with_rank(&mut rng, 2);
+    /// assert!(Lu::new(&a).det().abs() < 1e-12);
+    /// ```
+    pub fn random_with_rank<R: Rng + ?Sized>(rng: &mut R, rank: usize) -> Self {
+        let two = T::one() + T::one();
+        let singular_values: Vec<T> = (0..rank).map(|_| rng.gen_range(T::one()..=two)).collect();
+        Self::random_with_singular_values(rng, &singular_values)
+    }
+}
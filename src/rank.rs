@@ -0,0 +1,130 @@
+use std::ops::{Div, Sub};
+
+use num_traits::{Float, One, Zero};
+
+use crate::{
+    AbsValue, ColumnVector, CommutativeRing, LargestMagnitudeAbove, Matrix, MatrixEntry, RowOps,
+};
+
+impl<
+        const M: usize,
+        const N: usize,
+        T: MatrixEntry + Div<Output = T> + Sub<Output = T> + Zero + One + CommutativeRing,
+    > Matrix<M, N, T>
+{
+    /// The rank of `self`, the number of nonzero rows in the row echelon form of a working copy.
+    ///
+    /// Pivots are compared with zero exactly, which suits exact scalars. For floating point matrices, whose rounding
+    /// errors leave tiny nonzero entries behind, use [`Matrix::rank_with_tol`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,f64>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    /// assert_eq!(a.rank(), 2);
+    /// assert_eq!(Matrix::<2,3,f64>::new([[0.0; 3]; 2]).rank(), 0);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let mut a = *self;
+        a.transform_to_row_echelon_form();
+        a.data
+            .iter()
+            .filter(|row| row.iter().any(|entry| !entry.is_zero()))
+            .count()
+    }
+
+    /// A basis of the column space, or image, of `self`, the pivot columns of `self`, whose positions are those of
+    /// the pivots in its row echelon form.
+    ///
+    /// Pivots are compared with zero exactly, as for [`Matrix::rank`].
+    /// For an orthonormal basis of a floating point matrix, use [`Matrix::orthonormal_column_space`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,f64>::new([[1.0, 2.0, 0.0], [2.0, 4.0, 1.0], [0.0, 0.0, 1.0]]);
+    ///
+    /// let basis = a.column_space();
+    ///
+    /// assert_eq!(basis, vec![Matrix::new([[1.0], [2.0], [0.0]]), Matrix::new([[0.0], [1.0], [1.0]])]);
+    /// assert_eq!(basis.len(), a.rank());
+    /// ```
+    pub fn column_space(&self) -> Vec<ColumnVector<M, T>> {
+        let mut a = *self;
+        a.transform_to_row_echelon_form();
+        a.data
+            .iter()
+            .filter_map(|row| row.iter().position(|entry| !entry.is_zero()))
+            .map(|pivot_col| Matrix::new(self.data.map(|row| [row[pivot_col]])))
+            .collect()
+    }
+
+    /// A basis of the null space of `self`, the vectors `x` with `Ax = 0`, read off the reduced row echelon form of a
+    /// working copy. There is one basis vector for each column without a pivot, with a one in that column's position,
+    /// so a matrix of full column rank has an empty basis.
+    ///
+    /// Pivots are compared with zero exactly, as for [`Matrix::rank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,f64>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 7.0]]);
+    ///
+    /// let basis = a.null_space();
+    ///
+    /// assert_eq!(basis, vec![Matrix::new([[-2.0], [1.0], [0.0]])]);
+    /// assert_eq!(a * basis[0], Matrix::new([[0.0], [0.0]]));
+    /// assert!(Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]).null_space().is_empty());
+    /// ```
+    pub fn null_space(&self) -> Vec<ColumnVector<N, T>> {
+        let mut a = *self;
+        a.transform_to_reduced_row_echelon_form();
+        let mut pivot_cols = [None; M];
+        for (pivot_col, row) in pivot_cols.iter_mut().zip(a.data.iter()) {
+            *pivot_col = row.iter().position(|entry| !entry.is_zero());
+        }
+        (0..N)
+            .filter(|free| !pivot_cols.contains(&Some(*free)))
+            .map(|free| {
+                let mut basis_vector = [[T::zero()]; N];
+                basis_vector[free] = [T::one()];
+                for (pivot_col, row) in pivot_cols.iter().zip(a.data.iter()) {
+                    if let Some(pivot_col) = pivot_col {
+                        basis_vector[*pivot_col] = [T::zero() - row[free]];
+                    }
+                }
+                Matrix::new(basis_vector)
+            })
+            .collect()
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float + AbsValue + CommutativeRing>
+    Matrix<M, N, T>
+{
+    /// The numerical rank of `self`, the number of pivots of magnitude greater than `tol` in Gaussian elimination
+    /// with partial pivoting, treating smaller pivots as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,f64>::new([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+    /// assert_eq!(a.rank_with_tol(1e-12), 2);
+    /// assert_eq!(a.rank_with_tol(1.0), 0);
+    /// ```
+    pub fn rank_with_tol(&self, tol: T) -> usize {
+        let mut a = *self;
+        let strategy = LargestMagnitudeAbove::new(tol);
+        let mut rank = 0;
+        for j in 0..N {
+            if rank < M && a.eliminate_column(rank, j, &strategy) {
+                rank += 1;
+            }
+        }
+        rank
+    }
+}
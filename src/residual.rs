@@ -0,0 +1,64 @@
+use num_traits::Float;
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Matrix, MatrixEntry};
+
+impl<
+        const M: usize,
+        const N: usize,
+        T: MatrixEntry + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    > Matrix<M, N, T>
+{
+    /// The residual `B - AX` of a candidate solution `x` to `AX = B`, where `A` is `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,i32>::new([[2, 1], [1, 3]]);
+    /// let b = Matrix::<2,1,i32>::new([[5], [10]]);
+    /// assert_eq!(a.residual(&b, &Matrix::new([[1], [3]])), Matrix::new([[0], [0]]));
+    /// assert_eq!(a.residual(&b, &Matrix::new([[2], [1]])), Matrix::new([[0], [5]]));
+    /// ```
+    pub fn residual<const P: usize>(
+        &self,
+        b: &Matrix<M, P, T>,
+        x: &Matrix<N, P, T>,
+    ) -> Matrix<M, P, T> {
+        *b - *self * *x
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, T> {
+    /// The normwise relative backward error `‖B - AX‖ / (‖A‖‖X‖ + ‖B‖)` of a candidate solution `x` to `AX = B`,
+    /// where `A` is `self` and all norms are Frobenius norms.
+    ///
+    /// This is the size of the smallest relative perturbation of `A` and `B` for which `x` is an exact solution,
+    /// up to a small constant, so a value near machine epsilon means `x` is as good as any stable algorithm could give.
+    /// If `A`, `X` and `B` are all zero, the error is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Factorization, Lu, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[1.0], [0.5]]);
+    ///
+    /// let x = Lu::new(&a).solve(&b).unwrap();
+    /// assert!(a.relative_backward_error(&b, &x) < 1e-15);
+    ///
+    /// let guess = Matrix::new([[0.0], [0.5]]);
+    /// assert!(a.relative_backward_error(&b, &guess) > 0.1);
+    /// ```
+    pub fn relative_backward_error<const P: usize>(
+        &self,
+        b: &Matrix<M, P, T>,
+        x: &Matrix<N, P, T>,
+    ) -> T {
+        let scale = self.frobenius_norm() * x.frobenius_norm() + b.frobenius_norm();
+        if scale.is_zero() {
+            return T::zero();
+        }
+        self.residual(b, x).frobenius_norm() / scale
+    }
+}
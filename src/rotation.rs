@@ -0,0 +1,304 @@
+use num_traits::{Float, One, Zero};
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+impl<T: MatrixEntry + Float> SquareMatrix<3, T> {
+    /// The rotation matrix `exp([ω]ₓ)` rotating by `|ω|` radians about the axis `ω`,
+    /// computed using Rodrigues' formula.
+    ///
+    /// # Examples
+    ///
+    /// A quarter turn about the `z` axis,
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let omega = Matrix::<3,1,f64>::new([[0.0], [0.0], [std::f64::consts::FRAC_PI_2]]);
+    /// let r = SquareMatrix::<3,f64>::so3_exp(&omega);
+    /// let expected = SquareMatrix::<3,f64>::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    /// assert!((r - expected).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn so3_exp(omega: &Matrix<3, 1, T>) -> Self {
+        let theta_squared = (omega.transpose() * *omega).data[0][0];
+        let theta = theta_squared.sqrt();
+        let k = hat(omega);
+        let (a, b) = if theta < T::epsilon().sqrt() {
+            let six = T::from(6.0).unwrap();
+            let twenty_four = T::from(24.0).unwrap();
+            (
+                T::one() - theta_squared / six,
+                T::one() / (T::one() + T::one()) - theta_squared / twenty_four,
+            )
+        } else {
+            (
+                theta.sin() / theta,
+                (T::one() - theta.cos()) / theta_squared,
+            )
+        };
+        Self::one() + k * a + k * k * b
+    }
+
+    /// The rotation vector `ω` of a rotation matrix, such that `so3_exp(ω)` is `self`.
+    /// The angle `|ω|` lies in `[0, π]`.
+    ///
+    /// The result is only meaningful if `self` is a rotation matrix. If any entry of `self` is NaN or infinite,
+    /// every entry of the result is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let r = SquareMatrix::<3,f64>::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    /// let omega = r.so3_log();
+    /// assert!((*omega.get_entry(2,0).unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn so3_log(&self) -> Matrix<3, 1, T> {
+        if !self.data.iter().flatten().all(|entry| entry.is_finite()) {
+            return Matrix::new([[T::nan()]; 3]);
+        }
+        let two = T::one() + T::one();
+        let trace = self.data[0][0] + self.data[1][1] + self.data[2][2];
+        let cos_theta = ((trace - T::one()) / two).max(-T::one()).min(T::one());
+        let skew = vee(&(*self - self.transpose()));
+        let sin_theta = (skew.transpose() * skew).data[0][0].sqrt() / two;
+        let theta = sin_theta.atan2(cos_theta);
+        if theta < T::epsilon().sqrt() {
+            skew * (T::one() / two)
+        } else if cos_theta >= T::zero() {
+            skew * (theta / (two * sin_theta))
+        } else {
+            // Near a half turn the skew part vanishes, so recover the axis from the symmetric part
+            // `(R + Rᵀ)/2 = cos(θ)I + (1 - cos(θ))nnᵀ`.
+            let symmetric = (*self + self.transpose()) * (T::one() / two);
+            let outer = (symmetric - Self::one() * cos_theta) * (T::one() / (T::one() - cos_theta));
+            let k = (1..3).fold(0, |k, i| {
+                if outer.data[i][i] > outer.data[k][k] {
+                    i
+                } else {
+                    k
+                }
+            });
+            let scale = outer.data[k][k].sqrt();
+            let mut axis = Matrix::<3, 1, T>::new([
+                [outer.data[0][k] / scale],
+                [outer.data[1][k] / scale],
+                [outer.data[2][k] / scale],
+            ]);
+            if (axis.transpose() * skew).data[0][0] < T::zero() {
+                axis = axis * -T::one();
+            }
+            axis * theta
+        }
+    }
+
+    /// Spherical linear interpolation from the rotation `self` (at `t = 0`) to the rotation `other` (at `t = 1`),
+    /// following the shortest geodesic `self·exp(t·log(selfᵀ·other))`.
+    ///
+    /// # Examples
+    ///
+    /// Halfway between the identity and a quarter turn about `z` is an eighth turn about `z`,
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let quarter_turn = SquareMatrix::<3,f64>::so3_exp(&Matrix::new([[0.0], [0.0], [std::f64::consts::FRAC_PI_2]]));
+    /// let eighth_turn = SquareMatrix::<3,f64>::so3_exp(&Matrix::new([[0.0], [0.0], [std::f64::consts::FRAC_PI_4]]));
+    ///
+    /// let halfway = SquareMatrix::<3,f64>::one().slerp_to(&quarter_turn, 0.5);
+    ///
+    /// assert!((halfway - eighth_turn).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn slerp_to(&self, other: &Self, t: T) -> Self {
+        let delta = (self.transpose() * *other).so3_log();
+        *self * Self::so3_exp(&(delta * t))
+    }
+}
+
+impl<T: MatrixEntry + Float> SquareMatrix<4, T> {
+    /// Interpolation from the rigid transform `self` (at `t = 0`) to the rigid transform `other` (at `t = 1`),
+    /// using [`slerp_to`](SquareMatrix::slerp_to) for the rotation blocks and linear interpolation for the translations.
+    ///
+    /// Both matrices are expected to be homogeneous transforms with a rotation in the upper-left 3-by-3 block,
+    /// a translation in the last column, and a last row of `[0, 0, 0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// let start = SquareMatrix::<4,f64>::one();
+    /// let end = SquareMatrix::<4,f64>::new([
+    ///     [0.0, -1.0, 0.0, 2.0],
+    ///     [1.0, 0.0, 0.0, 4.0],
+    ///     [0.0, 0.0, 1.0, 6.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// let halfway = start.interpolate_transform_to(&end, 0.5);
+    ///
+    /// let c = std::f64::consts::FRAC_1_SQRT_2;
+    /// let expected = SquareMatrix::<4,f64>::new([
+    ///     [c, -c, 0.0, 1.0],
+    ///     [c, c, 0.0, 2.0],
+    ///     [0.0, 0.0, 1.0, 3.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert!((halfway - expected).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    /// ```
+    pub fn interpolate_transform_to(&self, other: &Self, t: T) -> Self {
+        let rotation = rotation_block(self).slerp_to(&rotation_block(other), t);
+        let mut transform = Self::one();
+        for i in 0..3 {
+            for j in 0..3 {
+                transform.data[i][j] = rotation.data[i][j];
+            }
+            transform.data[i][3] = self.data[i][3] + (other.data[i][3] - self.data[i][3]) * t;
+        }
+        transform
+    }
+}
+
+/// The chordal L2 mean of `rotations`, the rotation `R` minimizing the sum of squared Frobenius distances `Σ ‖R - Rᵢ‖²`,
+/// found by projecting the sum `M = Σ Rᵢ` onto the nearest rotation.
+///
+/// The nearest rotation is `U diag(1, 1, ±1) Vᵀ` for the singular value decomposition `M = UΣVᵀ`, with the sign chosen
+/// so the result is a proper rotation rather than a reflection. `V` and `Σ²` come from the [symmetric eigendecomposition](SquareMatrix::symmetric_eigen) of `MᵀM`.
+/// If `rotations` is empty, or the sum has rank less than two so that the mean is not unique, get [`None`] instead.
+///
+/// # Examples
+///
+/// The mean of turns by `±0.2` radians about `z` is the identity, while the mean of turns by `0.1` and `0.3` about `z`
+/// is a turn by `0.2` about `z`.
+///
+/// ```
+/// # use num_traits::One;
+/// # use malg::{mean_rotation, Matrix, SquareMatrix};
+/// let turn = |angle: f64| SquareMatrix::<3,f64>::so3_exp(&Matrix::new([[0.0], [0.0], [angle]]));
+///
+/// let mean = mean_rotation(&[turn(0.2), turn(-0.2)]).unwrap();
+/// assert!((mean - SquareMatrix::one()).frobenius_norm() < 1e-12);
+///
+/// let mean = mean_rotation(&[turn(0.1), turn(0.3)]).unwrap();
+/// assert!((mean - turn(0.2)).frobenius_norm() < 1e-12);
+///
+/// assert_eq!(mean_rotation::<f64>(&[]), None);
+/// ```
+pub fn mean_rotation<T: MatrixEntry + Float>(
+    rotations: &[SquareMatrix<3, T>],
+) -> Option<SquareMatrix<3, T>> {
+    let sum = rotations
+        .iter()
+        .fold(SquareMatrix::<3, T>::zero(), |sum, rotation| {
+            sum + *rotation
+        });
+    let eigen = (sum.transpose() * sum).symmetric_eigen().ok()?;
+    let (eigenvalues, v) = (eigen.get_eigenvalues(), eigen.get_eigenvectors());
+    let column =
+        |j: usize| Matrix::<3, 1, T>::new([[v.data[0][j]], [v.data[1][j]], [v.data[2][j]]]);
+    let singular_values = eigenvalues.map(|lambda| lambda.max(T::zero()).sqrt());
+    if singular_values[1] <= T::epsilon().sqrt() * singular_values[2] {
+        return None;
+    }
+    let (v_1, v_2) = (column(2), column(1));
+    let u_1 = sum * v_1 * singular_values[2].recip();
+    let u_2 = sum * v_2 * singular_values[1].recip();
+    // Completing both bases with cross products makes each proper, so the product is a rotation.
+    let v_3 = hat(&v_1) * v_2;
+    let u_3 = hat(&u_1) * u_2;
+    Some(u_1 * v_1.transpose() + u_2 * v_2.transpose() + u_3 * v_3.transpose())
+}
+
+/// The skew-symmetric cross product matrix `[ω]ₓ` of `omega`.
+fn hat<T: MatrixEntry + Float>(omega: &Matrix<3, 1, T>) -> SquareMatrix<3, T> {
+    let [[x], [y], [z]] = omega.data;
+    let o = T::zero();
+    SquareMatrix::<3, T>::new([[o, -z, y], [z, o, -x], [-y, x, o]])
+}
+
+/// The vector `ω` of a matrix whose skew-symmetric part is `[ω]ₓ`, scaled by two.
+fn vee<T: MatrixEntry + Float>(a: &SquareMatrix<3, T>) -> Matrix<3, 1, T> {
+    Matrix::<3, 1, T>::new([[a.data[2][1]], [a.data[0][2]], [a.data[1][0]]])
+}
+
+/// The upper-left 3-by-3 block of a homogeneous transform.
+fn rotation_block<T: MatrixEntry + Float>(transform: &SquareMatrix<4, T>) -> SquareMatrix<3, T> {
+    let mut rotation = SquareMatrix::<3, T>::zero();
+    for i in 0..3 {
+        for j in 0..3 {
+            rotation.data[i][j] = transform.data[i][j];
+        }
+    }
+    rotation
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    fn max_abs_difference(a: &SquareMatrix<3, f64>, b: &SquareMatrix<3, f64>) -> f64 {
+        (*a - *b)
+            .as_slice()
+            .iter()
+            .flatten()
+            .fold(0.0, |acc, e| acc.max(e.abs()))
+    }
+
+    /// Check the log map inverts the exp map for small, moderate, and near half-turn angles
+    #[test]
+    fn check_so3_log_inverts_exp() -> Result<(), Box<dyn Error>> {
+        for angle in [1e-9, 0.3, 2.0, std::f64::consts::PI - 1e-7] {
+            let axis = [[1.0 / 3.0], [-2.0 / 3.0], [2.0 / 3.0]];
+            let omega = Matrix::<3, 1, f64>::new(axis) * angle;
+            let r = SquareMatrix::<3, f64>::so3_exp(&omega);
+            let recovered = r.so3_log();
+            for i in 0..3 {
+                let difference = recovered.get_entry(i, 0).ok_or("No value")?
+                    - omega.get_entry(i, 0).ok_or("No value")?;
+                assert!(difference.abs() < 1e-6, "angle {angle}: {recovered:?}");
+            }
+            let r_again = SquareMatrix::<3, f64>::so3_exp(&recovered);
+            assert!(max_abs_difference(&r, &r_again) < 1e-12);
+        }
+        Ok(())
+    }
+
+    /// Check the log map of a [`Matrix`] with non-finite entries is NaN rather than a panic
+    #[test]
+    fn check_so3_log_non_finite() -> Result<(), Box<dyn Error>> {
+        for bad in [f64::NAN, f64::INFINITY] {
+            let mut r =
+                SquareMatrix::<3, f64>::new([[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]]);
+            r.data[0][0] = bad;
+            let omega = r.so3_log();
+            assert!(omega.as_slice().iter().flatten().all(|e| e.is_nan()));
+        }
+        Ok(())
+    }
+
+    /// Check slerp reaches both endpoints
+    #[test]
+    fn check_slerp_endpoints() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::so3_exp(&Matrix::new([[0.1], [0.2], [0.3]]));
+        let b = SquareMatrix::<3, f64>::so3_exp(&Matrix::new([[-1.0], [0.5], [2.0]]));
+        assert!(max_abs_difference(&a.slerp_to(&b, 0.0), &a) < 1e-12);
+        assert!(max_abs_difference(&a.slerp_to(&b, 1.0), &b) < 1e-12);
+        Ok(())
+    }
+
+    /// Check the mean of perturbations of a rotation in opposite directions is that rotation
+    #[test]
+    fn check_mean_rotation_of_symmetric_perturbations() -> Result<(), Box<dyn Error>> {
+        let r = SquareMatrix::<3, f64>::so3_exp(&Matrix::new([[0.4], [-1.2], [2.5]]));
+        let mut rotations = Vec::new();
+        for delta in [[0.1, 0.0, 0.05], [-0.02, 0.3, 0.0]] {
+            let delta = Matrix::<3, 1, f64>::new(delta.map(|d| [d]));
+            rotations.push(r * SquareMatrix::so3_exp(&delta));
+            rotations.push(r * SquareMatrix::so3_exp(&(delta * -1.0)));
+        }
+        let mean = mean_rotation(&rotations).ok_or("No unique mean")?;
+        assert!(max_abs_difference(&mean, &r) < 1e-12);
+        assert!((mean.determinant() - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+}
@@ -1,8 +1,11 @@
-use std::ops::{Div, Sub};
+use std::{
+    cmp::Ordering,
+    ops::{Add, ControlFlow, Div, Range, Sub},
+};
 
 use num_traits::{One, Zero};
 
-use crate::MatrixEntry;
+use crate::{ignore_progress, Matrix, MatrixEntry, MatrixError, Progress};
 
 /// Provides a set of elementary row operations for an object, where elements of the object are scaled by type `Scalar`
 pub trait RowOps<Scalar: MatrixEntry + Div<Output = Scalar> + Sub<Output = Scalar> + Zero + One> {
@@ -14,32 +17,575 @@ pub trait RowOps<Scalar: MatrixEntry + Div<Output = Scalar> + Sub<Output = Scala
     fn add_rows(&mut self, i: usize, j: usize, a: Scalar);
     /// The `i`th row of `self`.
     fn get_row(&self, i: usize) -> Vec<Scalar>;
+    /// The entry in row `i` and column `j` of `self`.
+    ///
+    /// The default implementation reads it from [`get_row`](RowOps::get_row). Implementations with direct access to
+    /// their entries should override it, so that row reduction does not allocate.
+    fn get_row_entry(&self, i: usize, j: usize) -> Scalar {
+        self.get_row(i)[j]
+    }
     /// Number of rows in `self`
     fn n_rows(&self) -> usize;
     /// Number of columns in `self`
     fn n_cols(&self) -> usize;
     /// Calculate the row echelon form of `self` in place.
-    fn transform_to_row_echelon_form(&mut self) {
-        let mut i = 0;
-        for j in 0..self.n_cols() {
-            let mut pivot_found = false;
-            for k in i..self.n_rows() {
-                if !self.get_row(k)[j].is_zero() {
-                    if pivot_found {
-                        let leading_entry = self.get_row(k)[j];
-                        self.add_rows(k, i, Scalar::zero() - Scalar::one() * leading_entry);
-                    } else {
-                        pivot_found = true;
-                        self.swap_rows(i, k);
-                        let pivot_value = self.get_row(i)[j];
-                        self.scale_row(i, Scalar::one() / pivot_value);
-                    }
+    ///
+    /// The pivot in each column is the first nonzero entry at or below the current row,
+    /// which only requires exact zero tests and so suits any field of scalars.
+    ///
+    /// Eliminating entries assumes scalars commute, so `Scalar` must be a [`CommutativeRing`].
+    fn transform_to_row_echelon_form(&mut self)
+    where
+        Scalar: CommutativeRing,
+    {
+        // Ignoring progress never cancels the reduction.
+        let _ = self.transform_to_row_echelon_form_observed(&FirstNonzero, &mut ignore_progress);
+    }
+    /// Calculate the row echelon form of `self` in place, using partial pivoting.
+    ///
+    /// The pivot in each column is the entry of largest [`Magnitude`] at or below the current row,
+    /// which limits the growth of rounding errors for floating point scalars.
+    ///
+    /// ## Examples
+    ///
+    /// Pivoting on a tiny entry destroys the information in the rest of the row,
+    ///
+    /// ```
+    /// # use malg::*;
+    /// let a = Matrix::<2,2,f64>::new([[1e-20, 1.0], [1.0, 1.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[1.0], [2.0]]);
+    ///
+    /// let mut unpivoted = a.augment(&b);
+    /// unpivoted.transform_to_row_echelon_form();
+    /// assert_eq!(*unpivoted.get_left(), Matrix::new([[1.0, 1e20], [0.0, 1.0]]));
+    /// ```
+    ///
+    /// while pivoting on the largest entry preserves it.
+    ///
+    /// ```
+    /// # use malg::*;
+    /// # let a = Matrix::<2,2,f64>::new([[1e-20, 1.0], [1.0, 1.0]]);
+    /// # let b = Matrix::<2,1,f64>::new([[1.0], [2.0]]);
+    /// let mut pivoted = a.augment(&b);
+    /// pivoted.transform_to_row_echelon_form_pivoted();
+    /// assert_eq!(*pivoted.get_left(), Matrix::new([[1.0, 1.0], [0.0, 1.0]]));
+    /// ```
+    fn transform_to_row_echelon_form_pivoted(&mut self)
+    where
+        Scalar: CommutativeRing + Magnitude,
+    {
+        // Ignoring progress never cancels the reduction.
+        let _ =
+            self.transform_to_row_echelon_form_observed(&LargestMagnitude, &mut ignore_progress);
+    }
+    /// Calculate the reduced row echelon form of `self` in place, the row echelon form with every entry above a pivot
+    /// cleared as well, so that the solution of an augmented system `[A|b]` with invertible `A` can be read off `b`.
+    ///
+    /// Eliminating entries assumes scalars commute, so `Scalar` must be a [`CommutativeRing`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use malg::*;
+    /// let a = Matrix::<2,2,f64>::new([[2.0, 1.0], [1.0, 3.0]]);
+    /// let b = Matrix::<2,1,f64>::new([[4.0], [7.0]]);
+    ///
+    /// let mut augmented = a.augment(&b);
+    /// augmented.transform_to_reduced_row_echelon_form();
+    ///
+    /// assert_eq!(*augmented.get_left(), Matrix::new([[1.0, 0.0], [0.0, 1.0]]));
+    /// assert_eq!(*augmented.get_right(), Matrix::new([[1.0], [2.0]]));
+    /// ```
+    fn transform_to_reduced_row_echelon_form(&mut self)
+    where
+        Scalar: CommutativeRing,
+    {
+        self.transform_to_row_echelon_form();
+        for i in (0..self.n_rows()).rev() {
+            let Some(col) = (0..self.n_cols()).find(|&j| !self.get_row_entry(i, j).is_zero())
+            else {
+                continue;
+            };
+            for k in 0..i {
+                let entry = self.get_row_entry(k, col);
+                if !entry.is_zero() {
+                    self.add_rows(k, i, Scalar::zero() - entry);
                 }
             }
-            if pivot_found {
+        }
+    }
+    /// Calculate the row echelon form of `self` in place, choosing pivots with `strategy` and reporting
+    /// [`Progress`] to `observer` after each column is eliminated, so that a frontend can display the
+    /// intermediate matrices or cancel the reduction.
+    ///
+    /// If `observer` returns [`ControlFlow::Break`], the reduction stops with the columns so far eliminated,
+    /// and [`MatrixError::Cancelled`] is returned.
+    ///
+    /// Eliminating entries assumes scalars commute, so `Scalar` must be a [`CommutativeRing`].
+    ///
+    /// ## Examples
+    ///
+    /// Stop after the first column,
+    ///
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use malg::*;
+    /// let mut a = Matrix::<2,3,f64>::new([[2.0, 4.0, 2.0], [1.0, 3.0, 4.0]]);
+    /// let mut steps = Vec::new();
+    ///
+    /// let result = a.transform_to_row_echelon_form_observed(&FirstNonzero, &mut |progress| {
+    ///     steps.push((progress.get_step(), progress.get_total()));
+    ///     if progress.get_step() == 1 {
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result, Err(MatrixError::Cancelled));
+    /// assert_eq!(steps, vec![(1, 3)]);
+    /// assert_eq!(a, Matrix::new([[1.0, 2.0, 1.0], [0.0, 1.0, 3.0]]));
+    /// ```
+    fn transform_to_row_echelon_form_observed(
+        &mut self,
+        strategy: &dyn PivotStrategy<Scalar>,
+        observer: &mut dyn FnMut(&Progress<Scalar>) -> ControlFlow<()>,
+    ) -> Result<(), MatrixError>
+    where
+        Scalar: CommutativeRing,
+    {
+        let total = self.n_cols();
+        let mut i = 0;
+        for j in 0..total {
+            if self.eliminate_column(i, j, strategy) {
                 i += 1;
             }
+            if observer(&Progress::new(j + 1, total, None)).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+        Ok(())
+    }
+    /// Eliminate the entries of column `col` below row `pivot_row`, the elementary step of row reduction.
+    ///
+    /// The pivot is chosen by `strategy` among the entries of the column at and below `pivot_row`, swapped into
+    /// `pivot_row`, and scaled to one, before multiples of the pivot row are subtracted from the rows below it.
+    /// Returns `false`, leaving `self` unchanged, if `strategy` finds no nonzero pivot.
+    ///
+    /// Eliminating entries assumes scalars commute, so `Scalar` must be a [`CommutativeRing`].
+    ///
+    /// ## Examples
+    ///
+    /// Reduce only the first column of a matrix,
+    ///
+    /// ```
+    /// # use malg::*;
+    /// let mut a = Matrix::<3,3,f64>::new([[1.0, 2.0, 1.0], [4.0, 4.0, 2.0], [2.0, 0.0, 3.0]]);
+    ///
+    /// assert!(a.eliminate_column(0, 0, &LargestMagnitude));
+    ///
+    /// assert_eq!(a, Matrix::new([[1.0, 1.0, 0.5], [0.0, 1.0, 0.5], [0.0, -2.0, 2.0]]));
+    /// ```
+    ///
+    /// or pick pivots with a custom [`PivotStrategy`], such as the last nonzero entry.
+    ///
+    /// ```
+    /// # use std::ops::Range;
+    /// # use malg::*;
+    /// # use num_traits::Zero;
+    /// struct LastNonzero;
+    /// impl PivotStrategy<f64> for LastNonzero {
+    ///     fn select_pivot(&self, rows: Range<usize>, entry: &dyn Fn(usize) -> f64) -> Option<usize> {
+    ///         rows.rev().find(|&k| !entry(k).is_zero())
+    ///     }
+    /// }
+    ///
+    /// let mut a = Matrix::<2,2,f64>::new([[1.0, 2.0], [2.0, 2.0]]);
+    /// assert!(a.eliminate_column(0, 0, &LastNonzero));
+    /// assert_eq!(a, Matrix::new([[1.0, 1.0], [0.0, 1.0]]));
+    /// assert!(!a.eliminate_column(1, 0, &LastNonzero));
+    /// ```
+    fn eliminate_column(
+        &mut self,
+        pivot_row: usize,
+        col: usize,
+        strategy: &dyn PivotStrategy<Scalar>,
+    ) -> bool
+    where
+        Scalar: CommutativeRing,
+    {
+        let rows = pivot_row..self.n_rows();
+        let Some(pivot) = strategy.select_pivot(rows.clone(), &|k| self.get_row_entry(k, col))
+        else {
+            return false;
+        };
+        if !rows.contains(&pivot) {
+            return false;
+        }
+        let pivot_value = self.get_row_entry(pivot, col);
+        if pivot_value.is_zero() {
+            return false;
+        }
+        self.swap_rows(pivot_row, pivot);
+        self.scale_row(pivot_row, Scalar::one() / pivot_value);
+        for k in pivot_row + 1..self.n_rows() {
+            let leading_entry = self.get_row_entry(k, col);
+            if !leading_entry.is_zero() {
+                self.add_rows(k, pivot_row, Scalar::zero() - leading_entry);
+            }
         }
+        true
+    }
+}
+
+/// Rule for choosing the pivot of a column in [`RowOps::eliminate_column`].
+pub trait PivotStrategy<Scalar> {
+    /// The row of the entry to pivot on among `rows`, the pivot row and those below it, where `entry(k)` is the entry
+    /// of the column being eliminated in row `k`. If no entry is suitable, get [`None`] instead.
+    fn select_pivot(&self, rows: Range<usize>, entry: &dyn Fn(usize) -> Scalar) -> Option<usize>;
+    /// Whether `entry` counts as zero, such as an entry of a row that has no pivot.
+    /// By default only an exact zero does.
+    fn is_negligible(&self, entry: &Scalar) -> bool
+    where
+        Scalar: Zero,
+    {
+        entry.is_zero()
+    }
+}
+
+/// Pivot on the first nonzero entry, which only requires exact zero tests and so suits any field of scalars.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub struct FirstNonzero;
+
+impl<Scalar: Zero> PivotStrategy<Scalar> for FirstNonzero {
+    fn select_pivot(
+        &self,
+        mut rows: Range<usize>,
+        entry: &dyn Fn(usize) -> Scalar,
+    ) -> Option<usize> {
+        rows.find(|&k| !entry(k).is_zero())
+    }
+}
+
+/// Pivot on the nonzero entry of largest [`Magnitude`], partial pivoting, which limits the growth of rounding errors
+/// for floating point scalars.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
+pub struct LargestMagnitude;
+
+impl<Scalar: Zero + Magnitude> PivotStrategy<Scalar> for LargestMagnitude {
+    fn select_pivot(&self, rows: Range<usize>, entry: &dyn Fn(usize) -> Scalar) -> Option<usize> {
+        rows.map(|k| (k, entry(k)))
+            .filter(|(_, entry)| !entry.is_zero())
+            .max_by(|(_, a), (_, b)| {
+                a.magnitude()
+                    .partial_cmp(&b.magnitude())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map(|(k, _)| k)
+    }
+}
+
+/// Pivot on the entry of largest [`Magnitude`], as [`LargestMagnitude`] does, but only if that magnitude exceeds a
+/// tolerance, so that entries left tiny by rounding errors count as zero.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::*;
+/// let mut a = Matrix::<2,2,f64>::new([[1e-14, 1.0], [-1e-13, 2.0]]);
+///
+/// assert!(!a.eliminate_column(0, 0, &LargestMagnitudeAbove::new(1e-12)));
+/// assert!(a.eliminate_column(0, 0, &LargestMagnitudeAbove::new(0.0)));
+/// assert_eq!(a.get_row_entry(0, 0), 1.0);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct LargestMagnitudeAbove<Tol> {
+    tol: Tol,
+}
+
+impl<Tol> LargestMagnitudeAbove<Tol> {
+    /// Pivot only on entries whose magnitude is greater than `tol`.
+    pub fn new(tol: Tol) -> Self {
+        LargestMagnitudeAbove { tol }
+    }
+}
+
+impl<Scalar: Zero + Magnitude> PivotStrategy<Scalar>
+    for LargestMagnitudeAbove<<Scalar as Magnitude>::Output>
+{
+    fn select_pivot(&self, rows: Range<usize>, entry: &dyn Fn(usize) -> Scalar) -> Option<usize> {
+        LargestMagnitude
+            .select_pivot(rows, entry)
+            .filter(|&k| entry(k).magnitude() > self.tol)
+    }
+    fn is_negligible(&self, entry: &Scalar) -> bool {
+        entry.magnitude() <= self.tol
+    }
+}
+
+/// Marker for scalars whose multiplication is commutative, `ab = ba`.
+///
+/// Algorithms whose results depend on the order of multiplication, such as row reduction, require this trait,
+/// so non-commutative scalars such as quaternions are rejected at compile time rather than giving wrong results.
+/// Entrywise operations such as addition, subtraction and transposition are available for any scalar.
+///
+/// ```compile_fail
+/// # use malg::*;
+/// #[derive(Clone, Copy, Default, PartialEq)]
+/// struct Quaternion([f64; 4]);
+/// # impl std::ops::Add for Quaternion { type Output = Self; fn add(self, _: Self) -> Self { self } }
+/// # impl std::ops::Sub for Quaternion { type Output = Self; fn sub(self, _: Self) -> Self { self } }
+/// # impl std::ops::Mul for Quaternion { type Output = Self; fn mul(self, _: Self) -> Self { self } }
+/// # impl std::ops::Div for Quaternion { type Output = Self; fn div(self, _: Self) -> Self { self } }
+/// # impl num_traits::Zero for Quaternion { fn zero() -> Self { Self::default() } fn is_zero(&self) -> bool { true } }
+/// # impl num_traits::One for Quaternion { fn one() -> Self { Self::default() } }
+///
+/// let mut a = Matrix::<2,2,Quaternion>::new([[Quaternion::default(); 2]; 2]);
+/// a.transform_to_row_echelon_form();
+/// ```
+///
+/// # Custom scalars
+///
+/// Row reduction of a scalar type defined outside this crate requires an implementation of this trait. For exact
+/// scalars such as finite fields, an empty implementation suffices, and pivots on the first nonzero entry.
+///
+/// ```
+/// # use malg::*;
+/// use std::ops::{Add, Div, Mul, Sub};
+///
+/// /// The field of integers modulo 7.
+/// #[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// struct F7(u8);
+///
+/// impl Add for F7 { type Output = Self; fn add(self, b: Self) -> Self { F7((self.0 + b.0) % 7) } }
+/// impl Sub for F7 { type Output = Self; fn sub(self, b: Self) -> Self { F7((self.0 + 7 - b.0) % 7) } }
+/// impl Mul for F7 { type Output = Self; fn mul(self, b: Self) -> Self { F7(self.0 * b.0 % 7) } }
+/// // b⁻¹ = b⁵ by Fermat's little theorem.
+/// impl Div for F7 { type Output = Self; fn div(self, b: Self) -> Self { self * b * b * b * b * b } }
+/// impl num_traits::Zero for F7 { fn zero() -> Self { F7(0) } fn is_zero(&self) -> bool { self.0 == 0 } }
+/// impl num_traits::One for F7 { fn one() -> Self { F7(1) } }
+///
+/// impl CommutativeRing for F7 {}
+///
+/// let mut a = Matrix::new([[F7(3), F7(1)], [F7(2), F7(4)]]);
+/// a.transform_to_row_echelon_form();
+/// assert_eq!(a, Matrix::new([[F7(1), F7(5)], [F7(0), F7(1)]]));
+/// ```
+pub trait CommutativeRing {
+    /// The [`PivotStrategy`] with which methods that take none, such as
+    /// [`AugmentedMatrix::reduce_left_to_identity`](crate::AugmentedMatrix::reduce_left_to_identity), reduce a matrix.
+    ///
+    /// By default the pivot is the first nonzero entry, [`FirstNonzero`], which suits exact scalars. Floating point
+    /// scalars instead pivot on the entry of largest magnitude above `max(M, N)·ε·‖A‖max`, [`LargestMagnitudeAbove`],
+    /// so that small pivots are avoided and entries left behind by rounding errors count as zero.
+    fn pivot_strategy<const M: usize, const N: usize>(
+        _matrix: Matrix<M, N, Self>,
+    ) -> impl PivotStrategy<Self>
+    where
+        Self: MatrixEntry + Zero,
+    {
+        FirstNonzero
+    }
+}
+
+macro_rules! impl_commutative_ring {
+    ($($t:ty),*) => {
+        $(impl CommutativeRing for $t {})*
+    };
+}
+impl_commutative_ring!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_commutative_ring_float {
+    ($($t:ty),*) => {
+        $(impl CommutativeRing for $t {
+            fn pivot_strategy<const M: usize, const N: usize>(
+                matrix: Matrix<M, N, $t>,
+            ) -> impl PivotStrategy<$t> {
+                LargestMagnitudeAbove::new(M.max(N) as $t * <$t>::EPSILON * matrix.norm_max())
+            }
+        })*
+    };
+}
+impl_commutative_ring_float!(f32, f64);
+
+/// Complex numbers over a commutative ring commute, so with the `num-complex` feature complex matrices can be
+/// row reduced.
+///
+/// ```
+/// # #[cfg(feature = "num-complex")]
+/// # {
+/// # use num_complex::Complex;
+/// # use malg::{Matrix, RowOps};
+/// let i = Complex::new(0.0, 1.0);
+/// let one = Complex::new(1.0, 0.0);
+/// let mut a = Matrix::<2,2,Complex<f64>>::new([[i, one], [one, i]]);
+/// a.transform_to_row_echelon_form();
+/// assert_eq!(a, Matrix::new([[one, -i], [Complex::new(0.0, 0.0), one]]));
+/// # }
+/// ```
+#[cfg(feature = "num-complex")]
+impl<T: CommutativeRing> CommutativeRing for num_complex::Complex<T> {}
+
+/// Rationals over a commutative ring commute, so with the `num-rational` feature matrices of exact rationals can be
+/// row reduced without rounding errors.
+///
+/// ```
+/// # #[cfg(feature = "num-rational")]
+/// # {
+/// # use num_rational::Ratio;
+/// # use malg::{Matrix, RowOps};
+/// let r = |n: i64, d: i64| Ratio::new(n, d);
+/// let mut a = Matrix::<2,3,Ratio<i64>>::new([[r(1, 3), r(1, 2), r(1, 1)], [r(1, 2), r(1, 3), r(0, 1)]]);
+/// a.transform_to_row_echelon_form();
+/// assert_eq!(a, Matrix::new([[r(1, 1), r(3, 2), r(3, 1)], [r(0, 1), r(1, 1), r(18, 5)]]));
+/// # }
+/// ```
+#[cfg(feature = "num-rational")]
+impl<T: CommutativeRing> CommutativeRing for num_rational::Ratio<T> {}
+
+/// Scalars with a magnitude that can be ordered, allowing pivots of largest magnitude to be selected.
+///
+/// Exact scalar types, such as finite fields, need not implement this trait. Scalars implementing [`AbsValue`]
+/// implement it automatically, measuring magnitude by absolute value. Scalars whose magnitude has a different type
+/// implement it directly, as complex numbers do with the `num-complex` feature.
+pub trait Magnitude {
+    /// Type the magnitude is measured in.
+    type Output: PartialOrd;
+    /// The magnitude, or absolute value, of `self`.
+    fn magnitude(&self) -> Self::Output;
+}
+
+impl<Scalar: AbsValue> Magnitude for Scalar {
+    type Output = Scalar;
+    fn magnitude(&self) -> Scalar {
+        self.abs_value()
+    }
+}
+
+/// With the `num-complex` feature, complex numbers measure magnitude by their modulus `|a + bi| = √(a² + b²)`,
+/// so complex matrices can be row reduced with partial pivoting.
+///
+/// ```
+/// # #[cfg(feature = "num-complex")]
+/// # {
+/// # use num_complex::Complex;
+/// # use malg::{LargestMagnitude, PivotStrategy};
+/// let column = [Complex::new(1.0, 1.0), Complex::new(0.0, -2.0), Complex::new(-1.5, 0.0)];
+/// assert_eq!(LargestMagnitude.select_pivot(0..3, &|k| column[k]), Some(1));
+/// # }
+/// ```
+#[cfg(feature = "num-complex")]
+impl<T: num_traits::Float> Magnitude for num_complex::Complex<T> {
+    type Output = T;
+    fn magnitude(&self) -> T {
+        self.norm()
+    }
+}
+
+/// Ordered scalars with an absolute value of the same type, such as floats, integers or rationals,
+/// so that magnitudes can be compared and summed uniformly.
+///
+/// Every `AbsValue` scalar has a [`Magnitude`], its absolute value, and so supports partial pivoting.
+///
+/// # Examples
+///
+/// An exact rational type only needs its absolute value to support partial pivoting.
+///
+/// ```
+/// # use malg::{AbsValue, LargestMagnitude, PivotStrategy};
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// struct Ratio(i64, i64);
+///
+/// impl PartialOrd for Ratio {
+///     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+///         (self.0 * other.1).partial_cmp(&(other.0 * self.1))
+///     }
+/// }
+///
+/// impl AbsValue for Ratio {
+///     fn abs_value(&self) -> Self {
+///         Ratio(self.0.abs(), self.1)
+///     }
+/// }
+/// # impl num_traits::Zero for Ratio { fn zero() -> Self { Ratio(0, 1) } fn is_zero(&self) -> bool { self.0 == 0 } }
+/// # impl std::ops::Add for Ratio { type Output = Self; fn add(self, o: Self) -> Self { Ratio(self.0 * o.1 + o.0 * self.1, self.1 * o.1) } }
+///
+/// let column = [Ratio(1, 2), Ratio(-2, 3), Ratio(3, 5)];
+/// assert_eq!(LargestMagnitude.select_pivot(0..3, &|k| column[k]), Some(1));
+/// ```
+pub trait AbsValue: PartialOrd + Sized {
+    /// The absolute value of `self`.
+    fn abs_value(&self) -> Self;
+    /// The sum of `self` and `rhs`, saturating at the largest value for integers, so that sums of absolute values,
+    /// such as norms, cannot overflow.
+    fn saturating_sum(self, rhs: Self) -> Self
+    where
+        Self: Add<Output = Self>,
+    {
+        self + rhs
+    }
+}
+
+macro_rules! impl_abs_value_signed_integer {
+    ($($t:ty),*) => {
+        $(impl AbsValue for $t {
+            /// The absolute value of `self`, saturating so that the most negative value maps to the largest positive value.
+            fn abs_value(&self) -> $t {
+                self.saturating_abs()
+            }
+            fn saturating_sum(self, rhs: $t) -> $t {
+                self.saturating_add(rhs)
+            }
+        })*
+    };
+}
+impl_abs_value_signed_integer!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_abs_value_unsigned_integer {
+    ($($t:ty),*) => {
+        $(impl AbsValue for $t {
+            fn abs_value(&self) -> $t {
+                *self
+            }
+            fn saturating_sum(self, rhs: $t) -> $t {
+                self.saturating_add(rhs)
+            }
+        })*
+    };
+}
+impl_abs_value_unsigned_integer!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_abs_value_float {
+    ($($t:ty),*) => {
+        $(impl AbsValue for $t {
+            fn abs_value(&self) -> $t {
+                self.abs()
+            }
+        })*
+    };
+}
+impl_abs_value_float!(f32, f64);
+
+/// With the `num-rational` feature, exact rationals measure magnitude by their absolute value, so they support partial
+/// pivoting and the norms built on [`AbsValue`].
+///
+/// ```
+/// # #[cfg(feature = "num-rational")]
+/// # {
+/// # use num_rational::Ratio;
+/// # use malg::Matrix;
+/// let r = |n: i64, d: i64| Ratio::new(n, d);
+/// let a = Matrix::<2,2,Ratio<i64>>::new([[r(1, 3), r(-3, 4)], [r(1, 2), r(0, 1)]]);
+/// assert_eq!(a.norm_max(), r(3, 4));
+/// # }
+/// ```
+#[cfg(feature = "num-rational")]
+impl<T> AbsValue for num_rational::Ratio<T>
+where
+    num_rational::Ratio<T>: num_traits::Signed + PartialOrd,
+{
+    fn abs_value(&self) -> Self {
+        num_traits::Signed::abs(self)
     }
 }
 
@@ -104,4 +650,46 @@ mod tests {
         );
         Ok(())
     }
+    /// Check partial pivoting picks the largest entry in each column of a [`Matrix`]
+    #[test]
+    fn check_pivoted_row_echelon_form() -> Result<(), Box<dyn Error>> {
+        let mut input_matrix =
+            Matrix::<3, 3, f64>::new([[2.0, 4.0, 2.0], [4.0, 8.0, 6.0], [1.0, 3.0, 1.0]]);
+        input_matrix.transform_to_row_echelon_form_pivoted();
+        let target_matrix =
+            Matrix::<3, 3, f64>::new([[1.0, 2.0, 1.5], [0.0, 1.0, -0.5], [0.0, 0.0, 1.0]]);
+        assert_eq!(input_matrix, target_matrix);
+        Ok(())
+    }
+    /// Check partial pivoting of a complex [`Matrix`] picks the entry of largest modulus
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn check_complex_pivoted_row_echelon_form() -> Result<(), Box<dyn Error>> {
+        use num_complex::Complex;
+
+        let i = Complex::new(0.0, 1.0);
+        let real = |x: f64| Complex::new(x, 0.0);
+        let mut input_matrix = Matrix::<2, 2, Complex<f64>>::new([[i, real(1.5)], [real(2.0), i]]);
+        input_matrix.transform_to_row_echelon_form_pivoted();
+        let target_matrix = Matrix::new([[real(1.0), i * 0.5], [real(0.0), real(1.0)]]);
+        assert_eq!(input_matrix, target_matrix);
+        Ok(())
+    }
+    /// Check the reduced row echelon form of a rank deficient [`Matrix`] clears the entries above each pivot
+    #[test]
+    fn check_reduced_row_echelon_form() -> Result<(), Box<dyn Error>> {
+        let mut input_matrix = Matrix::<3, 4, f64>::new([
+            [1.0, 2.0, 1.0, 4.0],
+            [2.0, 4.0, 0.0, 6.0],
+            [1.0, 2.0, 2.0, 5.0],
+        ]);
+        input_matrix.transform_to_reduced_row_echelon_form();
+        let target_matrix = Matrix::<3, 4, f64>::new([
+            [1.0, 2.0, 0.0, 3.0],
+            [0.0, 0.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ]);
+        assert_eq!(input_matrix, target_matrix);
+        Ok(())
+    }
 }
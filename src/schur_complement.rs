@@ -0,0 +1,54 @@
+use num_traits::Float;
+
+use crate::{dim, Factorization, Lu, MatrixEntry, MatrixError, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The Schur complement `D - CA⁻¹B` of the leading `K`-by-`K` block `A` in the 2-by-2 blocking
+    ///
+    /// ```text
+    /// [A B]
+    /// [C D]
+    /// ```
+    ///
+    /// of `self`, where `D` is `L`-by-`L`. If `A` is singular, get [`MatrixError::Singular`] instead.
+    ///
+    /// `K + L` must equal `N`, which is checked at compile time. `L` can usually be inferred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, 0.0, 2.0], [0.0, 4.0, 4.0], [2.0, 4.0, 9.0]]);
+    ///
+    /// let s: SquareMatrix<1,f64> = a.block_schur_complement::<2, _>().unwrap();
+    ///
+    /// assert_eq!(s, SquareMatrix::<1,f64>::new([[3.0]]));
+    /// ```
+    ///
+    /// A singular pivot block is reported as an error.
+    ///
+    /// ```
+    /// # use malg::{MatrixError, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[0.0, 1.0], [1.0, 1.0]]);
+    /// assert_eq!(a.block_schur_complement::<1, 1>(), Err(MatrixError::Singular));
+    /// ```
+    ///
+    /// Block sizes that do not add up to `N` fail to compile.
+    ///
+    /// ```compile_fail
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, 0.0, 2.0], [0.0, 4.0, 4.0], [2.0, 4.0, 9.0]]);
+    /// let s = a.block_schur_complement::<2, 2>();
+    /// ```
+    pub fn block_schur_complement<const K: usize, const L: usize>(
+        &self,
+    ) -> Result<SquareMatrix<L, T>, MatrixError> {
+        const { dim::assert_sum(N, K, L, "block sizes must sum to the matrix size") };
+        let a = self.submatrix::<K, K>(0, 0);
+        let b = self.submatrix::<K, L>(0, K);
+        let c = self.submatrix::<L, K>(K, 0);
+        let d = self.submatrix::<L, L>(K, K);
+        let a_inv_b = Lu::new(&a).solve(&b).ok_or(MatrixError::Singular)?;
+        Ok(d - c * a_inv_b)
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{dim, Matrix, MatrixEntry};
+
+impl<const M: usize, const N: usize, T: MatrixEntry> Matrix<M, N, T> {
+    /// The `K` consecutive rows of `self` starting at row `I`, using zero-based indexing.
+    ///
+    /// Rows outside of the matrix fail to compile, rather than panicking at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<4,2,u8>::new([[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// assert_eq!(a.rows::<1, 2>(), Matrix::new([[3, 4], [5, 6]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use malg::Matrix;
+    /// let a = Matrix::<4,2,u8>::new([[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// let b = a.rows::<3, 2>();
+    /// ```
+    pub fn rows<const I: usize, const K: usize>(&self) -> Matrix<K, N, T> {
+        const { dim::assert_within(I, K, M, "rows lie outside of the matrix") };
+        self.submatrix::<K, N>(I, 0)
+    }
+
+    /// The `K` consecutive columns of `self` starting at column `J`, using zero-based indexing.
+    ///
+    /// Columns outside of the matrix fail to compile, rather than panicking at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,4,u8>::new([[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// assert_eq!(a.cols::<0, 2>(), Matrix::new([[1, 2], [5, 6]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,4,u8>::new([[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// let b = a.cols::<2, 3>();
+    /// ```
+    pub fn cols<const J: usize, const K: usize>(&self) -> Matrix<M, K, T> {
+        const { dim::assert_within(J, K, N, "columns lie outside of the matrix") };
+        self.submatrix::<M, K>(0, J)
+    }
+
+    /// The `P`-by-`Q` block of `self` whose upper-left entry is the (`I`, `J`)<sup>th</sup> entry, using zero-based indexing.
+    ///
+    /// Blocks extending outside of the matrix fail to compile, rather than panicking at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,u8>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(a.block::<1, 1, 2, 2>(), Matrix::new([[5, 6], [8, 9]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,u8>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let b = a.block::<2, 0, 2, 2>();
+    /// ```
+    pub fn block<const I: usize, const J: usize, const P: usize, const Q: usize>(
+        &self,
+    ) -> Matrix<P, Q, T> {
+        const { dim::assert_within(I, P, M, "block lies outside of the matrix") };
+        const { dim::assert_within(J, Q, N, "block lies outside of the matrix") };
+        self.submatrix::<P, Q>(I, J)
+    }
+
+    /// `self` with row `I` removed, using zero-based indexing, such as when dropping an observation or building a minor.
+    ///
+    /// The number of rows `P` of the result is checked to be `M - 1`, and rows outside of the matrix fail to compile,
+    /// rather than panicking at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,u8>::new([[1, 2], [3, 4], [5, 6]]);
+    /// let b: Matrix<2, 2, u8> = a.remove_row::<1, 2>();
+    /// assert_eq!(b, Matrix::new([[1, 2], [5, 6]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,u8>::new([[1, 2], [3, 4], [5, 6]]);
+    /// let b = a.remove_row::<3, 2>();
+    /// ```
+    pub fn remove_row<const I: usize, const P: usize>(&self) -> Matrix<P, N, T> {
+        const { dim::assert_minor(P, M, "removing a row leaves one fewer row") };
+        const { dim::assert_within(I, 1, M, "row lies outside of the matrix") };
+        let mut rows = [[T::default(); N]; P];
+        for (k, row) in rows.iter_mut().enumerate() {
+            *row = self.data[if k < I { k } else { k + 1 }];
+        }
+        Matrix::new(rows)
+    }
+
+    /// `self` with column `J` removed, using zero-based indexing, such as when dropping a feature or building a minor.
+    ///
+    /// The number of columns `Q` of the result is checked to be `N - 1`, and columns outside of the matrix fail to
+    /// compile, rather than panicking at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,u8>::new([[1, 2, 3], [4, 5, 6]]);
+    /// let b: Matrix<2, 2, u8> = a.remove_col::<0, 2>();
+    /// assert_eq!(b, Matrix::new([[2, 3], [5, 6]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,u8>::new([[1, 2, 3], [4, 5, 6]]);
+    /// let b = a.remove_col::<1, 3>();
+    /// ```
+    pub fn remove_col<const J: usize, const Q: usize>(&self) -> Matrix<M, Q, T> {
+        const { dim::assert_minor(Q, N, "removing a column leaves one fewer column") };
+        const { dim::assert_within(J, 1, N, "column lies outside of the matrix") };
+        Matrix::new(self.data.map(|row| {
+            let mut entries = [T::default(); Q];
+            for (k, entry) in entries.iter_mut().enumerate() {
+                *entry = row[if k < J { k } else { k + 1 }];
+            }
+            entries
+        }))
+    }
+
+    /// Iterate over the `M - K + 1` overlapping blocks of `K` consecutive rows of `self`, from the top down,
+    /// such as the windows of a sliding window computation over the rows of a grid.
+    /// Windows taller than the matrix fail to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,u8>::new([[1, 2], [3, 4], [5, 6]]);
+    /// let mut windows = a.row_windows::<2>();
+    /// assert_eq!(windows.next(), Some(Matrix::new([[1, 2], [3, 4]])));
+    /// assert_eq!(windows.next(), Some(Matrix::new([[3, 4], [5, 6]])));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    pub fn row_windows<const K: usize>(&self) -> impl Iterator<Item = Matrix<K, N, T>> + '_ {
+        const { dim::assert_at_least(M, K, "windows cannot have more rows than the matrix") };
+        (0..=M - K).map(|i| self.submatrix::<K, N>(i, 0))
+    }
+
+    /// Iterate over the `N - K + 1` overlapping blocks of `K` consecutive columns of `self`, from left to right,
+    /// such as the windows of a sliding window computation over the columns of a grid.
+    /// Windows wider than the matrix fail to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let signal = Matrix::<1,5,i32>::new([[1, 3, 2, 5, 4]]);
+    /// let kernel = Matrix::<3,1,i32>::new([[1], [0], [-1]]);
+    ///
+    /// let convolved: Vec<i32> = signal
+    ///     .col_windows::<3>()
+    ///     .map(|window| (window * kernel).as_slice()[0][0])
+    ///     .collect();
+    ///
+    /// assert_eq!(convolved, vec![-1, -2, -2]);
+    /// ```
+    pub fn col_windows<const K: usize>(&self) -> impl Iterator<Item = Matrix<M, K, T>> + '_ {
+        const { dim::assert_at_least(N, K, "windows cannot have more columns than the matrix") };
+        (0..=N - K).map(|j| self.submatrix::<M, K>(0, j))
+    }
+
+    /// The matrix whose `k`<sup>th</sup> row is row `rows[k]` of `self`, using zero-based indexing,
+    /// such as a subsample or reordering of the observations in the rows of a data matrix.
+    /// Rows may be selected more than once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is not less than `M`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,u8>::new([[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a.select_rows([2, 0]), Matrix::new([[5, 6], [1, 2]]));
+    /// assert_eq!(a.select_rows([1, 1, 1, 1]), Matrix::new([[3, 4]; 4]));
+    /// ```
+    pub fn select_rows<const K: usize>(&self, rows: [usize; K]) -> Matrix<K, N, T> {
+        Matrix::new(rows.map(|i| self.data[i]))
+    }
+
+    /// The matrix whose `k`<sup>th</sup> column is column `cols[k]` of `self`, using zero-based indexing,
+    /// such as a subset or reordering of the features in the columns of a data matrix.
+    /// Columns may be selected more than once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,3,u8>::new([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.select_columns([2, 0, 2]), Matrix::new([[3, 1, 3], [6, 4, 6]]));
+    /// ```
+    pub fn select_columns<const K: usize>(&self, cols: [usize; K]) -> Matrix<M, K, T> {
+        Matrix::new(self.data.map(|row| cols.map(|j| row[j])))
+    }
+}
@@ -1,13 +1,13 @@
-use num_traits::{One, Zero};
-use std::ops::Mul;
+use num_traits::{ConstOne, ConstZero, Float, One, Zero};
+use std::ops::{Div, Mul, Sub};
 
-use crate::{Matrix, MatrixEntry};
+use crate::{AbsValue, AugmentedMatrix, CommutativeRing, Factorization, Lu, Matrix, MatrixEntry};
 
 /// `N`-by-`N` square matrix with entries of type `T`.
 pub type SquareMatrix<const N: usize, T> = Matrix<N, N, T>;
 
-impl<const N: usize, T: MatrixEntry + Mul<Output = T>> SquareMatrix<N, T> {
-    /// The trace of a square matrix.
+impl<const N: usize, T: MatrixEntry + Zero> SquareMatrix<N, T> {
+    /// The trace of a square matrix, the sum of its diagonal entries, [`SquareMatrix::diagonal_sum`].
     ///
     /// # Examples
     ///
@@ -24,12 +24,44 @@ impl<const N: usize, T: MatrixEntry + Mul<Output = T>> SquareMatrix<N, T> {
     /// let trace = b.trace();
     /// assert_eq!(trace, 4)
     /// ```
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let c = SquareMatrix::<2,u8>::new([[3, 1], [1, 5]]);
+    /// assert_eq!(c.trace(), 8)
+    /// ```
     pub fn trace(&self) -> T {
-        let mut trace = self.data[0][0];
-        for i in 1..N {
-            trace = trace * self.data[i][i];
-        }
-        trace
+        self.diagonal_sum()
+    }
+
+    /// The sum of the diagonal entries of a square matrix, zero for an empty matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,i32>::new([[2, 7, 1], [0, -3, 4], [5, 6, 4]]);
+    /// assert_eq!(a.diagonal_sum(), 3);
+    /// ```
+    pub fn diagonal_sum(&self) -> T {
+        (0..N).fold(T::zero(), |sum, i| sum + self.data[i][i])
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Mul<Output = T> + One> SquareMatrix<N, T> {
+    /// The product of the diagonal entries of a square matrix, one for an empty matrix.
+    /// For a triangular matrix this is its determinant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let u = SquareMatrix::<3,i32>::new([[2, 7, 1], [0, -3, 4], [0, 0, 4]]);
+    /// assert_eq!(u.diagonal_product(), -24);
+    /// assert_eq!(u.determinant_exact(), Ok(-24));
+    /// ```
+    pub fn diagonal_product(&self) -> T {
+        (0..N).fold(T::one(), |product, i| product * self.data[i][i])
     }
 }
 
@@ -52,3 +84,296 @@ impl<const N: usize, T: MatrixEntry + One + Zero> One for SquareMatrix<N, T> {
         identity
     }
 }
+
+impl<const N: usize, T: MatrixEntry + ConstZero + ConstOne> SquareMatrix<N, T> {
+    /// The `N`-by-`N` identity matrix, usable in const contexts such as the initializers of statics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// const I: SquareMatrix<3, i32> = SquareMatrix::IDENTITY;
+    /// assert_eq!(I, SquareMatrix::one());
+    /// ```
+    pub const IDENTITY: Self = {
+        let mut data = [[T::ZERO; N]; N];
+        let mut i = 0;
+        while i < N {
+            data[i][i] = T::ONE;
+            i += 1;
+        }
+        Matrix { data }
+    };
+}
+
+impl<const N: usize, T: MatrixEntry + One + Zero> SquareMatrix<N, T> {
+    /// Append the `N`-by-`N` identity matrix onto `self`, creating the augmented matrix `[self|I]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{AugmentedMatrix, Matrix, SquareMatrix};
+    /// let a = SquareMatrix::<2,u8>::new([[1, 2], [3, 4]]);
+    /// let a_i = a.augment_identity();
+    /// assert_eq!(*a_i.get_right(), Matrix::<2,2,u8>::new([[1, 0], [0, 1]]));
+    /// ```
+    pub fn augment_identity(&self) -> AugmentedMatrix<N, N, N, T> {
+        self.augment(&Matrix::<N, N, T>::one())
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + One + Zero> SquareMatrix<N, T> {
+    /// The polynomial `p(A) = c₀Aᵏ + c₁Aᵏ⁻¹ + ⋯ + cₖI` evaluated at `self` using Horner's scheme,
+    /// where `coeffs` lists `c₀, c₁, …, cₖ` from the highest degree down.
+    /// An empty list of coefficients evaluates to the zero matrix.
+    ///
+    /// # Examples
+    ///
+    /// `A² - 2A + I = (A - I)²` vanishes for this nilpotent perturbation of the identity,
+    ///
+    /// ```
+    /// # use num_traits::Zero;
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,i32>::new([[1, 1], [0, 1]]);
+    /// assert_eq!(a.polyval(&[1, -2, 1]), SquareMatrix::zero());
+    /// assert_eq!(a.polyval(&[1, 0, 0]), a * a);
+    /// ```
+    pub fn polyval(&self, coeffs: &[T]) -> Self {
+        coeffs.iter().fold(Self::zero(), |value, coeff| {
+            value * *self + Self::one() * *coeff
+        })
+    }
+
+    /// The power `Aᵏ` of `self` with `k = exp`, found by repeated squaring in at most `2⌊log₂ k⌋` products.
+    /// The zeroth power is the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// Powers of the Fibonacci matrix hold consecutive Fibonacci numbers,
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// let f = SquareMatrix::<2,u64>::new([[1, 1], [1, 0]]);
+    /// assert_eq!(f.pow(10), SquareMatrix::new([[89, 55], [55, 34]]));
+    /// assert_eq!(f.pow(0), SquareMatrix::one());
+    /// ```
+    ///
+    /// and powers of an adjacency matrix count walks, here the closed walks of length four around a triangle.
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let triangle = SquareMatrix::<3,u32>::new([[0, 1, 1], [1, 0, 1], [1, 1, 0]]);
+    /// assert_eq!(*triangle.pow(4).get_entry(0, 0).unwrap(), 6);
+    /// ```
+    pub fn pow(&self, exp: u32) -> Self {
+        let mut result = Self::one();
+        let mut square = *self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * square;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                square = square * square;
+            }
+        }
+        result
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + One + Zero + Sub<Output = T>> SquareMatrix<N, T> {
+    /// The companion matrix of the monic polynomial `xᴺ + c₀xᴺ⁻¹ + ⋯ + cₙ₋₁`,
+    /// where `coeffs` lists `c₀, c₁, …, cₙ₋₁` from the highest degree down.
+    /// The first row holds the negated coefficients and the subdiagonal holds ones,
+    /// so the eigenvalues of the companion matrix are the roots of the polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let c = SquareMatrix::<3,i32>::from_companion(&[-6, 11, -6]);
+    /// assert_eq!(c, SquareMatrix::new([[6, -11, 6], [1, 0, 0], [0, 1, 0]]));
+    /// ```
+    ///
+    /// A companion matrix is a root of its own polynomial.
+    ///
+    /// ```
+    /// # use num_traits::Zero;
+    /// # use malg::SquareMatrix;
+    /// let c = SquareMatrix::<3,i32>::from_companion(&[-6, 11, -6]);
+    /// assert_eq!(c.polyval(&[1, -6, 11, -6]), SquareMatrix::zero());
+    /// ```
+    pub fn from_companion(coeffs: &[T; N]) -> Self {
+        let mut companion = Self::zero();
+        if let Some(first_row) = companion.data.first_mut() {
+            for (entry, coeff) in first_row.iter_mut().zip(coeffs) {
+                *entry = T::zero() - *coeff;
+            }
+        }
+        for i in 1..N {
+            companion.data[i][i - 1] = T::one();
+        }
+        companion
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + One + Zero> SquareMatrix<N, T> {
+    /// The `N`-by-`N` Jordan block `J(λ)`, with `lambda` on the diagonal and ones on the superdiagonal.
+    /// Its only eigenvalue is `lambda`, with a single eigenvector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let j = SquareMatrix::<3,i32>::jordan_block(2);
+    /// assert_eq!(j, SquareMatrix::new([[2, 1, 0], [0, 2, 1], [0, 0, 2]]));
+    /// ```
+    pub fn jordan_block(lambda: T) -> Self {
+        let mut jordan = Self::shift();
+        for i in 0..N {
+            jordan.data[i][i] = lambda;
+        }
+        jordan
+    }
+
+    /// The `N`-by-`N` upper shift matrix, with ones on the superdiagonal.
+    /// Multiplying a column vector by it shifts the entries up by one, and its `N`th power is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::Zero;
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let s = SquareMatrix::<3,i32>::shift();
+    /// assert_eq!(s * Matrix::new([[1], [2], [3]]), Matrix::new([[2], [3], [0]]));
+    /// assert_eq!(s * s * s, SquareMatrix::zero());
+    /// ```
+    pub fn shift() -> Self {
+        let mut shift = Self::zero();
+        for i in 1..N {
+            shift.data[i - 1][i] = T::one();
+        }
+        shift
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The determinant of a square matrix, the signed product of the pivots found by row reduction with partial pivoting,
+    /// as in its [`Lu`] factorization. Each row swap flips the sign.
+    ///
+    /// For exact determinants of integer matrices, see [`SquareMatrix::determinant_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[0.0, 2.0, 1.0], [1.0, -1.0, 4.0], [3.0, 5.0, -2.0]]);
+    /// assert!((a.determinant() - 36.0).abs() < 1e-12);
+    ///
+    /// let singular = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.determinant(), 0.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        Lu::new(self).det()
+    }
+
+    /// The [determinant](SquareMatrix::determinant) of a square matrix by row reduction with partial pivoting,
+    /// and whether `self` is numerically singular, with a pivot no larger than `Nε‖A‖∞` in magnitude or not a number.
+    ///
+    /// Partial pivoting makes the computed determinant that of a nearby matrix, but near singularity it is dominated by
+    /// rounding errors in the small pivot, so the flag tells when the value should not be trusted. A numerically
+    /// singular matrix has a determinant of zero, rather than whatever the rounding errors left behind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[4.0, 3.0], [6.0, 3.0]]);
+    /// assert_eq!(a.determinant_with_pivoting(), (-6.0, false));
+    ///
+    /// // The last pivot is a rounding error rather than zero.
+    /// let singular = SquareMatrix::<3,f64>::new([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+    /// assert_ne!(singular.determinant(), 0.0);
+    /// assert_eq!(singular.determinant_with_pivoting(), (0.0, true));
+    ///
+    /// // A pivot that is not a number cannot be trusted either.
+    /// let nan = SquareMatrix::<2,f64>::new([[1.0, f64::NAN], [2.0, 1.0]]);
+    /// assert_eq!(nan.determinant_with_pivoting(), (0.0, true));
+    /// ```
+    pub fn determinant_with_pivoting(&self) -> (T, bool)
+    where
+        T: AbsValue,
+    {
+        let tol = T::from(N).unwrap_or_else(T::one) * T::epsilon() * self.norm_inf();
+        let lu = Lu::new(self);
+        let u = lu.get_u();
+        if u.data
+            .iter()
+            .enumerate()
+            .any(|(i, row)| row[i].is_nan() || row[i].abs() <= tol)
+        {
+            (T::zero(), true)
+        } else {
+            (lu.det(), false)
+        }
+    }
+}
+
+impl<
+        const N: usize,
+        T: MatrixEntry + Div<Output = T> + Sub<Output = T> + Zero + One + CommutativeRing,
+    > SquareMatrix<N, T>
+{
+    /// The inverse of a square matrix, found by Gauss–Jordan elimination of the augmented matrix `[self|I]`
+    /// with [`AugmentedMatrix::reduce_left_to_identity`], which pivots by largest magnitude for floating point
+    /// matrices. If `self` is singular, or singular up to rounding errors, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 0.0, 4.0]]);
+    /// let a_inv = a.inverse().unwrap();
+    /// assert_eq!(a * a_inv, SquareMatrix::one());
+    ///
+    /// let singular = SquareMatrix::<2,f64>::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<SquareMatrix<N, T>> {
+        let mut a_i = self.augment_identity();
+        if a_i.reduce_left_to_identity() {
+            Some(*a_i.get_right())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check the inverse of a [`Matrix`] with a tiny leading entry is found by pivoting on the larger entry below it
+    #[test]
+    fn check_inverse_tiny_pivot() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<2, f64>::new([[1e-20, 1.0], [1.0, 1.0]]);
+        let a_inv = a.inverse().ok_or("matrix is invertible")?;
+        assert_eq!(a_inv, SquareMatrix::new([[-1.0, 1.0], [1.0, -1e-20]]));
+        assert_eq!(Some(a_inv), Lu::new(&a).inverse());
+        Ok(())
+    }
+
+    /// Check a singular [`Matrix`] whose elimination leaves a rounding error as its last pivot has no inverse
+    #[test]
+    fn check_inverse_numerically_singular() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<3, f64>::new([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+        assert_eq!(a.inverse(), None);
+        Ok(())
+    }
+}
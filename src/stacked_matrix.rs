@@ -0,0 +1,137 @@
+use std::ops::{Add, Mul};
+
+use crate::{ColOps, Matrix, MatrixEntry};
+
+/// `(M+P)`-by-`N` rectangular matrix formed by stacking a `M`-by-`N` matrix `A` on top of a `P`-by-`N` matrix `B`.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub struct StackedMatrix<const M: usize, const P: usize, const N: usize, T: MatrixEntry> {
+    top: Matrix<M, N, T>,
+    bottom: Matrix<P, N, T>,
+}
+
+impl<const M: usize, const P: usize, const N: usize, T: MatrixEntry> StackedMatrix<M, P, N, T> {
+    /// Place matrix `b` below the matrix `a`, creating the stacked matrix with `a` on top of `b`.
+    pub fn new(a: Matrix<M, N, T>, b: Matrix<P, N, T>) -> StackedMatrix<M, P, N, T> {
+        StackedMatrix::<M, P, N, T> { top: a, bottom: b }
+    }
+    /// Upper part of the stacked matrix.
+    pub fn get_top(&self) -> &Matrix<M, N, T> {
+        &self.top
+    }
+    /// Lower part of the stacked matrix.
+    pub fn get_bottom(&self) -> &Matrix<P, N, T> {
+        &self.bottom
+    }
+}
+
+impl<
+        const M: usize,
+        const P: usize,
+        const N: usize,
+        T: MatrixEntry + Mul<Output = T> + Add<Output = T>,
+    > ColOps<T> for StackedMatrix<M, P, N, T>
+{
+    /// Swap columns `i` and `j` in place.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` or `j` are out of bounds. That is `i>=N` or `j>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// Swapping the columns of a stacked matrix affects both the top and bottom parts.
+    ///
+    /// ```
+    /// # use malg::{ColOps, Matrix};
+    /// let a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<1,3,u8>::new([[7,8,9]]);
+    ///
+    /// let a_swapped = Matrix::<2,3,u8>::new([[3,2,1], [6,5,4]]);
+    /// let b_swapped = Matrix::<1,3,u8>::new([[9,8,7]]);
+    ///
+    /// let mut c = a.augment_below(&b);
+    /// c.swap_cols(0,2);
+    ///
+    /// assert_eq!(c, a_swapped.augment_below(&b_swapped))
+    /// ```
+    fn swap_cols(&mut self, i: usize, j: usize) {
+        self.top.swap_cols(i, j);
+        self.bottom.swap_cols(i, j);
+    }
+    /// Scale column `i` by scalar value `a` in place.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` is out of bounds. That is `i>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// Scaling a column of a stacked matrix scales both the top and bottom parts.
+    ///
+    /// ```
+    /// # use malg::{ColOps, Matrix};
+    /// let a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<1,3,u8>::new([[7,8,9]]);
+    ///
+    /// let a_scaled = Matrix::<2,3,u8>::new([[1,4,3], [4,10,6]]);
+    /// let b_scaled = Matrix::<1,3,u8>::new([[7,16,9]]);
+    ///
+    /// let mut c = a.augment_below(&b);
+    /// c.scale_col(1,2);
+    ///
+    /// assert_eq!(c, a_scaled.augment_below(&b_scaled))
+    /// ```
+    fn scale_col(&mut self, i: usize, a: T) {
+        self.top.scale_col(i, a);
+        self.bottom.scale_col(i, a);
+    }
+    /// Replace column `i` with the sum of column `i` and `a` times column `j`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` or `j` are out of bounds. That is `i>=N` or `j>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// Summing the columns of a stacked matrix affects both the top and bottom parts.
+    ///
+    /// ```
+    /// # use malg::{ColOps, Matrix};
+    /// let a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<1,3,u8>::new([[7,8,9]]);
+    ///
+    /// let a_summed = Matrix::<2,3,u8>::new([[1,2,5], [4,5,14]]);
+    /// let b_summed = Matrix::<1,3,u8>::new([[7,8,23]]);
+    ///
+    /// let mut c = a.augment_below(&b);
+    /// c.add_cols(2,0,2);
+    ///
+    /// assert_eq!(c, a_summed.augment_below(&b_summed))
+    /// ```
+    fn add_cols(&mut self, i: usize, j: usize, a: T) {
+        self.top.add_cols(i, j, a);
+        self.bottom.add_cols(i, j, a);
+    }
+    /// The `i`th column of `self`
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i` is out of bounds. That is `i>=N`.
+    ///
+    /// ## Examples
+    ///
+    /// `get_col` returns only the top part of the stacked matrix.
+    ///
+    /// ```
+    /// # use malg::{ColOps, Matrix};
+    /// let a = Matrix::<2,3,u8>::new([[1,2,3], [4,5,6]]);
+    /// let b = Matrix::<1,3,u8>::new([[7,8,9]]);
+    ///
+    /// let col = a.augment_below(&b).get_col(1);
+    ///
+    /// assert_eq!(col, vec![2,5])
+    /// ```
+    fn get_col(&self, i: usize) -> Vec<T> {
+        self.top.get_col(i)
+    }
+}
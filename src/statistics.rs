@@ -0,0 +1,180 @@
+use num_traits::Float;
+
+use crate::{dim, Factorization, Lu, Matrix, MatrixEntry, MatrixError, SquareMatrix};
+
+/// Multivariate normal distribution over `N` variables with entries of type `T`,
+/// described by its mean vector and covariance matrix.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Gaussian<const N: usize, T: MatrixEntry> {
+    mean: Matrix<N, 1, T>,
+    covariance: SquareMatrix<N, T>,
+}
+
+impl<const N: usize, T: MatrixEntry + Float> Gaussian<N, T> {
+    /// A new [`Gaussian`] with the given `mean` and symmetric positive semi-definite `covariance`.
+    pub fn new(mean: Matrix<N, 1, T>, covariance: SquareMatrix<N, T>) -> Gaussian<N, T> {
+        Gaussian::<N, T> { mean, covariance }
+    }
+    /// Mean vector of the distribution.
+    pub fn get_mean(&self) -> &Matrix<N, 1, T> {
+        &self.mean
+    }
+    /// Covariance matrix of the distribution.
+    pub fn get_covariance(&self) -> &SquareMatrix<N, T> {
+        &self.covariance
+    }
+
+    /// The marginal distribution of the first `K` variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Gaussian, Matrix, SquareMatrix};
+    /// let mean = Matrix::<3,1,f64>::new([[1.0], [2.0], [3.0]]);
+    /// let covariance = SquareMatrix::<3,f64>::new([[4.0, 1.0, 0.5], [1.0, 3.0, 0.2], [0.5, 0.2, 2.0]]);
+    ///
+    /// let marginal = Gaussian::new(mean, covariance).marginal_leading::<2>();
+    ///
+    /// assert_eq!(*marginal.get_mean(), Matrix::<2,1,f64>::new([[1.0], [2.0]]));
+    /// assert_eq!(*marginal.get_covariance(), SquareMatrix::<2,f64>::new([[4.0, 1.0], [1.0, 3.0]]));
+    /// ```
+    pub fn marginal_leading<const K: usize>(&self) -> Gaussian<K, T> {
+        const { dim::assert_within(0, K, N, "cannot marginalize onto more variables than exist") };
+        Gaussian::<K, T>::new(
+            self.mean.submatrix::<K, 1>(0, 0),
+            self.covariance.submatrix::<K, K>(0, 0),
+        )
+    }
+
+    /// The marginal distribution of the last `L` variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Gaussian, Matrix, SquareMatrix};
+    /// let mean = Matrix::<3,1,f64>::new([[1.0], [2.0], [3.0]]);
+    /// let covariance = SquareMatrix::<3,f64>::new([[4.0, 1.0, 0.5], [1.0, 3.0, 0.2], [0.5, 0.2, 2.0]]);
+    ///
+    /// let marginal = Gaussian::new(mean, covariance).marginal_trailing::<1>();
+    ///
+    /// assert_eq!(*marginal.get_mean(), Matrix::<1,1,f64>::new([[3.0]]));
+    /// assert_eq!(*marginal.get_covariance(), SquareMatrix::<1,f64>::new([[2.0]]));
+    /// ```
+    pub fn marginal_trailing<const L: usize>(&self) -> Gaussian<L, T> {
+        const { dim::assert_within(0, L, N, "cannot marginalize onto more variables than exist") };
+        Gaussian::<L, T>::new(
+            self.mean.submatrix::<L, 1>(N - L, 0),
+            self.covariance.submatrix::<L, L>(N - L, N - L),
+        )
+    }
+
+    /// The distribution of the last `L` variables conditioned on observing the values `observed` for the first `K` variables.
+    ///
+    /// The conditional covariance is the Schur complement of the covariance of the observed variables,
+    /// see [`block_schur_complement`](SquareMatrix::block_schur_complement).
+    /// If that covariance is singular, get [`MatrixError::Singular`] instead.
+    ///
+    /// `K + L` must equal `N`, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Gaussian, Matrix, SquareMatrix};
+    /// let mean = Matrix::<2,1,f64>::new([[0.0], [0.0]]);
+    /// let covariance = SquareMatrix::<2,f64>::new([[1.0, 0.5], [0.5, 1.0]]);
+    ///
+    /// let conditional = Gaussian::new(mean, covariance)
+    ///     .condition_on_leading::<1, 1>(&Matrix::new([[1.0]]))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*conditional.get_mean(), Matrix::<1,1,f64>::new([[0.5]]));
+    /// assert_eq!(*conditional.get_covariance(), SquareMatrix::<1,f64>::new([[0.75]]));
+    /// ```
+    pub fn condition_on_leading<const K: usize, const L: usize>(
+        &self,
+        observed: &Matrix<K, 1, T>,
+    ) -> Result<Gaussian<L, T>, MatrixError> {
+        let covariance = self.covariance.block_schur_complement::<K, L>()?;
+        let observed_covariance = self.covariance.submatrix::<K, K>(0, 0);
+        let cross_covariance = self.covariance.submatrix::<L, K>(K, 0);
+        let innovation = *observed - self.mean.submatrix::<K, 1>(0, 0);
+        let gain = Lu::new(&observed_covariance)
+            .solve(&innovation)
+            .ok_or(MatrixError::Singular)?;
+        let mean = self.mean.submatrix::<L, 1>(K, 0) + cross_covariance * gain;
+        Ok(Gaussian::<L, T>::new(mean, covariance))
+    }
+
+    /// The distribution of the first `K` variables conditioned on observing the values `observed` for the last `L` variables.
+    /// If the covariance of the observed variables is singular, get [`MatrixError::Singular`] instead.
+    ///
+    /// `K + L` must equal `N`, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Gaussian, Matrix, SquareMatrix};
+    /// let mean = Matrix::<2,1,f64>::new([[0.0], [0.0]]);
+    /// let covariance = SquareMatrix::<2,f64>::new([[1.0, 0.5], [0.5, 1.0]]);
+    ///
+    /// let conditional = Gaussian::new(mean, covariance)
+    ///     .condition_on_trailing::<1, 1>(&Matrix::new([[-2.0]]))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*conditional.get_mean(), Matrix::<1,1,f64>::new([[-1.0]]));
+    /// assert_eq!(*conditional.get_covariance(), SquareMatrix::<1,f64>::new([[0.75]]));
+    /// ```
+    pub fn condition_on_trailing<const K: usize, const L: usize>(
+        &self,
+        observed: &Matrix<L, 1, T>,
+    ) -> Result<Gaussian<K, T>, MatrixError> {
+        const { dim::assert_sum(N, K, L, "block sizes must sum to the number of variables") };
+        let mut mean = self.mean;
+        let mut covariance = self.covariance;
+        let order = |i: usize| if i < L { i + K } else { i - L };
+        for i in 0..N {
+            mean.data[i][0] = self.mean.data[order(i)][0];
+            for j in 0..N {
+                covariance.data[i][j] = self.covariance.data[order(i)][order(j)];
+            }
+        }
+        Gaussian::<N, T>::new(mean, covariance).condition_on_leading::<L, K>(observed)
+    }
+}
+
+/// The covariance matrix `(1/n) Σ (pᵢ - p̄)(pᵢ - p̄)ᵀ` of the `n` points `points` about their centroid `p̄`,
+/// or `None` if there are no points.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{covariance_of_points, SquareMatrix};
+/// let points = [[1.0, 0.0, 2.0], [-1.0, 0.0, 2.0], [0.0, 2.0, 2.0], [0.0, -2.0, 2.0]];
+/// let covariance = covariance_of_points(&points);
+/// assert_eq!(covariance, Some(SquareMatrix::<3,f64>::new([[0.5, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.0]])));
+/// ```
+pub fn covariance_of_points<T: MatrixEntry + Float>(
+    points: &[[T; 3]],
+) -> Option<SquareMatrix<3, T>> {
+    if points.is_empty() {
+        return None;
+    }
+    let n = T::from(points.len())?;
+    let mut centroid = [T::zero(); 3];
+    for point in points {
+        for (c, p) in centroid.iter_mut().zip(point) {
+            *c = *c + *p;
+        }
+    }
+    let centroid = centroid.map(|c| c / n);
+    let mut covariance = SquareMatrix::<3, T>::new([[T::zero(); 3]; 3]);
+    for point in points {
+        let d = [0, 1, 2].map(|i| point[i] - centroid[i]);
+        for (row, d_i) in covariance.data.iter_mut().zip(d) {
+            for (entry, d_j) in row.iter_mut().zip(d) {
+                *entry = *entry + d_i * d_j;
+            }
+        }
+    }
+    Some(covariance * n.recip())
+}
@@ -0,0 +1,240 @@
+use std::ops::ControlFlow;
+
+use num_traits::Float;
+
+use crate::{ignore_progress, Matrix, MatrixEntry, MatrixError, Progress, SquareMatrix};
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The doubly stochastic matrix `D₁AD₂` found by the Sinkhorn–Knopp algorithm, which alternately scales the rows
+    /// and then the columns of `self` to sum to one.
+    ///
+    /// `self` should have positive entries. Iteration stops once every row sum is within `tol` of one after scaling
+    /// the columns, and if this does not happen within `iters` iterations, or a row or column sums to zero so that
+    /// scaling it is undefined, get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    ///
+    /// let s = a.sinkhorn_normalize(1000, 1e-12).unwrap();
+    ///
+    /// for i in 0..3 {
+    ///     let row_sum: f64 = s.as_slice()[i].iter().sum();
+    ///     let col_sum: f64 = s.as_slice().iter().map(|row| row[i]).sum();
+    ///     assert!((row_sum - 1.0).abs() <= 1e-12);
+    ///     assert!((col_sum - 1.0).abs() <= 1e-12);
+    /// }
+    /// ```
+    ///
+    /// A matrix with zero entries may have no doubly stochastic scaling.
+    ///
+    /// ```
+    /// # use malg::{MatrixError, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 1.0]]);
+    /// assert_eq!(a.sinkhorn_normalize(100, 1e-12), Err(MatrixError::NotConverged));
+    /// ```
+    pub fn sinkhorn_normalize(&self, iters: usize, tol: T) -> Result<Self, MatrixError> {
+        self.sinkhorn_normalize_observed(iters, tol, &mut ignore_progress)
+    }
+
+    /// The doubly stochastic matrix found by [`sinkhorn_normalize`](SquareMatrix::sinkhorn_normalize), reporting
+    /// [`Progress`] to `observer` after each iteration, with the largest deviation of a row sum from one as the residual.
+    ///
+    /// If `observer` returns [`ControlFlow::Break`], iteration stops and [`MatrixError::Cancelled`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use malg::{MatrixError, SquareMatrix};
+    /// let a = SquareMatrix::<2,f64>::new([[1.0, 1.0], [0.0, 1.0]]);
+    /// let mut residuals = Vec::new();
+    ///
+    /// let result = a.sinkhorn_normalize_observed(1000, 1e-12, &mut |progress| {
+    ///     residuals.push(progress.get_residual().unwrap());
+    ///     if residuals.len() < 10 {
+    ///         ControlFlow::Continue(())
+    ///     } else {
+    ///         ControlFlow::Break(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result, Err(MatrixError::Cancelled));
+    /// assert_eq!(residuals.len(), 10);
+    /// assert!(residuals.windows(2).all(|pair| pair[1] < pair[0]));
+    /// ```
+    pub fn sinkhorn_normalize_observed(
+        &self,
+        iters: usize,
+        tol: T,
+        observer: &mut dyn FnMut(&Progress<T>) -> ControlFlow<()>,
+    ) -> Result<Self, MatrixError> {
+        let mut a = *self;
+        for step in 1..=iters {
+            for row in a.data.iter_mut() {
+                let sum = row.iter().fold(T::zero(), |sum, entry| sum + *entry);
+                for entry in row.iter_mut() {
+                    *entry = *entry / sum;
+                }
+            }
+            for j in 0..N {
+                let sum = a.data.iter().fold(T::zero(), |sum, row| sum + row[j]);
+                for row in a.data.iter_mut() {
+                    row[j] = row[j] / sum;
+                }
+            }
+            let residual = a.data.iter().fold(T::zero(), |largest, row| {
+                let sum = row.iter().fold(T::zero(), |sum, entry| sum + *entry);
+                let deviation = (sum - T::one()).abs();
+                if deviation > largest || deviation.is_nan() {
+                    deviation
+                } else {
+                    largest
+                }
+            });
+            if residual.is_nan() {
+                return Err(MatrixError::NotConverged);
+            }
+            if residual <= tol {
+                return Ok(a);
+            }
+            if observer(&Progress::new(step, iters, Some(residual))).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+        Err(MatrixError::NotConverged)
+    }
+
+    /// Whether every entry of `self` is non-negative and every column sums to within `tol` of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<2,f64>::new([[0.25, 1.0], [0.75, 0.0]]);
+    /// assert!(a.is_column_stochastic(1e-12));
+    /// assert!(!a.transpose().is_column_stochastic(1e-12));
+    /// ```
+    pub fn is_column_stochastic(&self, tol: T) -> bool {
+        (0..N).all(|j| {
+            let sum = self.data.iter().fold(T::zero(), |sum, row| sum + row[j]);
+            self.data.iter().all(|row| row[j] >= T::zero()) && (sum - T::one()).abs() <= tol
+        })
+    }
+
+    /// The PageRank vector of the column stochastic link matrix `self`, where entry `(i, j)` is the probability
+    /// of following a link from page `j` to page `i`.
+    ///
+    /// The ranks are the dominant eigenvector of `dA + (1-d)/N 𝟙𝟙ᵀ` for the damping factor `d`, normalized to sum to one,
+    /// found by power iteration. Pages with no links, zero columns of `self`, link to every page equally.
+    /// Iteration stops once the ranks change by at most `tol` in the 1-norm, and if this does not happen within
+    /// 10,000 iterations, get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::SquareMatrix;
+    /// // Page 0 links to pages 1 and 2, page 1 links to page 2, and page 2 links to page 0.
+    /// let links = SquareMatrix::<3,f64>::new([[0.0, 0.0, 1.0], [0.5, 0.0, 0.0], [0.5, 1.0, 0.0]]);
+    /// assert!(links.is_column_stochastic(1e-12));
+    ///
+    /// let ranks = links.pagerank(0.85, 1e-12).unwrap();
+    ///
+    /// let ranks = ranks.as_slice().map(|[rank]| rank);
+    /// assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    /// assert!(ranks[2] > ranks[0] && ranks[0] > ranks[1]);
+    /// ```
+    pub fn pagerank(&self, damping: T, tol: T) -> Result<Matrix<N, 1, T>, MatrixError> {
+        self.pagerank_observed(damping, tol, &mut ignore_progress)
+    }
+
+    /// The PageRank vector found by [`pagerank`](SquareMatrix::pagerank), reporting [`Progress`] to `observer`
+    /// after each iteration, with the change in the ranks in the 1-norm as the residual.
+    ///
+    /// If `observer` returns [`ControlFlow::Break`], iteration stops and [`MatrixError::Cancelled`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use malg::{MatrixError, SquareMatrix};
+    /// let links = SquareMatrix::<3,f64>::new([[0.0, 0.0, 1.0], [0.5, 0.0, 0.0], [0.5, 1.0, 0.0]]);
+    ///
+    /// let mut steps = 0;
+    /// let ranks = links.pagerank_observed(0.85, 1e-12, &mut |progress| {
+    ///     steps = progress.get_step();
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!(ranks, links.pagerank(0.85, 1e-12));
+    /// assert!(steps > 0);
+    ///
+    /// let cancelled = links.pagerank_observed(0.85, 1e-12, &mut |_| ControlFlow::Break(()));
+    /// assert_eq!(cancelled, Err(MatrixError::Cancelled));
+    /// ```
+    pub fn pagerank_observed(
+        &self,
+        damping: T,
+        tol: T,
+        observer: &mut dyn FnMut(&Progress<T>) -> ControlFlow<()>,
+    ) -> Result<Matrix<N, 1, T>, MatrixError> {
+        const MAX_ITERATIONS: usize = 10_000;
+        let n = T::from(N).ok_or(MatrixError::Overflow)?;
+        let dangling: Vec<bool> = (0..N)
+            .map(|j| self.data.iter().all(|row| row[j].is_zero()))
+            .collect();
+        let mut ranks = Matrix::<N, 1, T>::new([[n.recip()]; N]);
+        for step in 1..=MAX_ITERATIONS {
+            let dangling_rank = ranks
+                .data
+                .iter()
+                .zip(&dangling)
+                .filter(|(_, &dangling)| dangling)
+                .fold(T::zero(), |sum, ([rank], _)| sum + *rank);
+            let teleport = (T::one() - damping + damping * dangling_rank) / n;
+            let mut next = *self * ranks * damping;
+            for [rank] in next.data.iter_mut() {
+                *rank = *rank + teleport;
+            }
+            let change = next
+                .data
+                .iter()
+                .zip(ranks.data.iter())
+                .fold(T::zero(), |sum, ([a], [b])| sum + (*a - *b).abs());
+            ranks = next;
+            if change <= tol {
+                return Ok(ranks);
+            }
+            if observer(&Progress::new(step, MAX_ITERATIONS, Some(change))).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+        Err(MatrixError::NotConverged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::*;
+
+    /// Check Sinkhorn–Knopp fails to converge, rather than returning NaN entries, for a [`SquareMatrix`] with a zero row
+    #[test]
+    fn check_sinkhorn_zero_row() -> Result<(), Box<dyn Error>> {
+        let a = SquareMatrix::<2, f64>::new([[0.0, 0.0], [1.0, 1.0]]);
+        assert_eq!(
+            a.sinkhorn_normalize(100, 1e-12),
+            Err(MatrixError::NotConverged)
+        );
+        let mut steps = 0;
+        let result = a.sinkhorn_normalize_observed(100, 1e-12, &mut |_| {
+            steps += 1;
+            std::ops::ControlFlow::Continue(())
+        });
+        assert_eq!(result, Err(MatrixError::NotConverged));
+        assert_eq!(steps, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use num_traits::{Signed, Zero};
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+/// Direction along which a matrix is traversed.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Axis {
+    /// Traverse each row.
+    Rows,
+    /// Traverse each column.
+    Columns,
+}
+
+impl<const N: usize, T: MatrixEntry + Signed + PartialOrd> SquareMatrix<N, T> {
+    /// Whether each diagonal entry of `self` is at least as large in magnitude as the sum of the magnitudes of the
+    /// other entries in its row (for [`Axis::Rows`]) or column (for [`Axis::Columns`]).
+    /// If `strict` is set, each diagonal entry must be strictly larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Axis, SquareMatrix};
+    /// let a = SquareMatrix::<3,i32>::new([[4, -1, 2], [1, 2, 1], [0, 5, 6]]);
+    ///
+    /// assert!(a.is_diagonally_dominant(Axis::Rows, false));
+    /// assert!(!a.is_diagonally_dominant(Axis::Rows, true));
+    /// assert!(!a.is_diagonally_dominant(Axis::Columns, false));
+    /// ```
+    pub fn is_diagonally_dominant(&self, axis: Axis, strict: bool) -> bool {
+        (0..N).all(|i| {
+            let off_diagonal = (0..N).filter(|&j| j != i).fold(T::zero(), |sum, j| {
+                let entry = match axis {
+                    Axis::Rows => self.data[i][j],
+                    Axis::Columns => self.data[j][i],
+                };
+                sum + entry.abs()
+            });
+            let diagonal = self.data[i][i].abs();
+            if strict {
+                diagonal > off_diagonal
+            } else {
+                diagonal >= off_diagonal
+            }
+        })
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Zero> Matrix<M, N, T> {
+    /// The lower and upper bandwidths of `self`, the largest distances below and above the diagonal of any nonzero entry.
+    ///
+    /// # Examples
+    ///
+    /// A tridiagonal matrix has lower and upper bandwidths of one,
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<4,4,i32>::new([[2, 1, 0, 0], [1, 2, 1, 0], [0, 1, 2, 1], [0, 0, 1, 2]]);
+    /// assert_eq!(a.bandwidth(), (1, 1));
+    /// ```
+    ///
+    /// and an upper triangular matrix has a lower bandwidth of zero.
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,3,i32>::new([[1, 0, 3], [0, 1, 0], [0, 0, 1]]);
+    /// assert_eq!(a.bandwidth(), (0, 2));
+    /// ```
+    pub fn bandwidth(&self) -> (usize, usize) {
+        let mut lower = 0;
+        let mut upper = 0;
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                if !entry.is_zero() {
+                    lower = lower.max(i.saturating_sub(j));
+                    upper = upper.max(j.saturating_sub(i));
+                }
+            }
+        }
+        (lower, upper)
+    }
+}
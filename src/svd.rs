@@ -0,0 +1,125 @@
+use num_traits::Float;
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+/// Maximum number of one-sided Jacobi sweeps when orthogonalizing the columns of a matrix.
+const MAX_SWEEPS: usize = 100;
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Float> Matrix<M, N, T> {
+    /// The Moore–Penrose pseudo-inverse `A⁺ = VΣ⁺Uᵀ` of `self`, from the singular value decomposition `A = UΣVᵀ`,
+    /// where `Σ⁺` inverts the singular values above the cutoff `max(M, N)·ε·σ₁` relative to the largest singular value
+    /// `σ₁` and sets the rest to zero.
+    ///
+    /// Rank-deficient and non-square matrices are handled alike: `A⁺b` is the least squares solution of `Ax ≈ b` of
+    /// smallest norm, and `A⁺ = A⁻¹` when `self` is invertible. The decomposition is found by one-sided Jacobi rotations
+    /// of the columns of `self`, stopping after 100 sweeps if the columns are not yet orthogonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<3,2,f64>::new([[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]]);
+    /// let a_pinv = a.pseudo_inverse();
+    /// assert!((a_pinv - Matrix::new([[1.0, 0.0, 0.0], [0.0, 0.5, 0.0]])).frobenius_norm() < 1e-12);
+    ///
+    /// // A rank one matrix, whose pseudo-inverse is `Aᵀ / ‖A‖²`.
+    /// let rank_one = Matrix::<2,3,f64>::new([[1.0, 2.0, 2.0], [1.0, 2.0, 2.0]]);
+    /// let pinv = rank_one.pseudo_inverse();
+    /// assert!((pinv - rank_one.transpose() * (1.0 / 18.0)).frobenius_norm() < 1e-12);
+    /// assert!((rank_one * pinv * rank_one - rank_one).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn pseudo_inverse(&self) -> Matrix<N, M, T> {
+        let (w, v) = self.one_sided_jacobi();
+        let singular_values = column_norms(&w);
+        let largest = singular_values
+            .iter()
+            .fold(T::zero(), |largest, sigma| largest.max(*sigma));
+        let cutoff = T::from(M.max(N)).unwrap_or_else(T::one) * T::epsilon() * largest;
+        let mut pinv = Matrix::<N, M, T>::new([[T::zero(); M]; N]);
+        for (pinv_row, v_row) in pinv.data.iter_mut().zip(v.data.iter()) {
+            for (entry, w_row) in pinv_row.iter_mut().zip(w.data.iter()) {
+                *entry = v_row
+                    .iter()
+                    .zip(w_row)
+                    .zip(singular_values.iter())
+                    .filter(|(_, sigma)| **sigma > cutoff)
+                    .fold(T::zero(), |sum, ((v_jk, w_ik), sigma)| {
+                        sum + *v_jk * *w_ik / (*sigma * *sigma)
+                    });
+            }
+        }
+        pinv
+    }
+
+    /// The singular values of `self`, the column norms of `W = AV` from [`Matrix::one_sided_jacobi`], in no particular
+    /// order, with zeros for the columns beyond the rank.
+    pub(crate) fn singular_values(&self) -> [T; N] {
+        column_norms(&self.one_sided_jacobi().0)
+    }
+
+    /// The matrices `W = AV` and `V` of a singular value decomposition `A = UΣVᵀ` of `self`, where `V` is orthogonal
+    /// and the columns of `W = UΣ` are mutually orthogonal with norms the singular values.
+    ///
+    /// Pairs of columns are rotated in cyclic sweeps until every pair is orthogonal relative to the product of their
+    /// norms, or for [`MAX_SWEEPS`] sweeps.
+    pub(crate) fn one_sided_jacobi(&self) -> (Matrix<M, N, T>, SquareMatrix<N, T>) {
+        let two = T::one() + T::one();
+        let mut w = *self;
+        let mut v = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+        for (i, row) in v.data.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in p + 1..N {
+                    let (alpha, beta, gamma) = w.data.iter().fold(
+                        (T::zero(), T::zero(), T::zero()),
+                        |(alpha, beta, gamma), row| {
+                            (
+                                alpha + row[p] * row[p],
+                                beta + row[q] * row[q],
+                                gamma + row[p] * row[q],
+                            )
+                        },
+                    );
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
+                    for row in w.data.iter_mut() {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                    for row in v.data.iter_mut() {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        (w, v)
+    }
+}
+
+/// The Euclidean norm of each column of `w`.
+fn column_norms<const M: usize, const N: usize, T: MatrixEntry + Float>(
+    w: &Matrix<M, N, T>,
+) -> [T; N] {
+    let mut norms = [T::zero(); N];
+    for row in w.data.iter() {
+        for (norm, entry) in norms.iter_mut().zip(row) {
+            *norm = *norm + *entry * *entry;
+        }
+    }
+    norms.map(|norm| norm.sqrt())
+}
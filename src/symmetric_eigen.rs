@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{MatrixEntry, MatrixError, SquareMatrix};
+
+/// Maximum number of Jacobi sweeps before giving up on diagonalizing a symmetric matrix.
+const MAX_SWEEPS: usize = 100;
+
+/// An eigendecomposition `A = VΛVᵀ` of a symmetric matrix `A`, with real eigenvalues `Λ` in ascending order
+/// and an orthogonal matrix `V` whose columns are corresponding unit eigenvectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymmetricEigen<const N: usize, T: MatrixEntry> {
+    eigenvalues: [T; N],
+    eigenvectors: SquareMatrix<N, T>,
+}
+
+impl<const N: usize, T: MatrixEntry> SymmetricEigen<N, T> {
+    /// The eigenvalues, in ascending order.
+    pub fn get_eigenvalues(&self) -> &[T; N] {
+        &self.eigenvalues
+    }
+
+    /// The orthogonal matrix `V` whose `j`th column is a unit eigenvector for the `j`th eigenvalue.
+    pub fn get_eigenvectors(&self) -> &SquareMatrix<N, T> {
+        &self.eigenvectors
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> SquareMatrix<N, T> {
+    /// The eigendecomposition of a symmetric matrix, such as a covariance or Gram matrix, by cyclic Jacobi rotations.
+    ///
+    /// Each rotation zeroes one off-diagonal entry, and sweeps over every entry repeat until the off-diagonal part is
+    /// negligible. Jacobi's method is slower than QR iteration but computes small eigenvalues to high relative accuracy
+    /// and gives eigenvectors orthogonal to working precision. Only the lower triangle of `self` is read.
+    ///
+    /// If the off-diagonal part is not negligible after 100 sweeps, get [`MatrixError::NotConverged`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::SquareMatrix;
+    /// let a = SquareMatrix::<3,f64>::new([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    ///
+    /// let eigen = a.symmetric_eigen().unwrap();
+    /// let v = *eigen.get_eigenvectors();
+    ///
+    /// let expected = [2.0 - 2f64.sqrt(), 2.0, 2.0 + 2f64.sqrt()];
+    /// for (eigenvalue, expected) in eigen.get_eigenvalues().iter().zip(expected) {
+    ///     assert!((eigenvalue - expected).abs() < 1e-12);
+    /// }
+    /// assert!((v.transpose() * v - SquareMatrix::one()).frobenius_norm() < 1e-12);
+    /// let mut lambda = SquareMatrix::<3,f64>::new([[0.0; 3]; 3]);
+    /// for (i, eigenvalue) in eigen.get_eigenvalues().iter().enumerate() {
+    ///     *lambda.get_mut_entry(i, i).unwrap() = *eigenvalue;
+    /// }
+    /// assert!((v * lambda * v.transpose() - a).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<N, T>, MatrixError> {
+        let (unsorted, v) = symmetric_jacobi(self).ok_or(MatrixError::NotConverged)?;
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_by(|&i, &j| {
+            unsorted[i]
+                .partial_cmp(&unsorted[j])
+                .unwrap_or(Ordering::Equal)
+        });
+        let mut eigenvectors = v;
+        for (row, v_row) in eigenvectors.data.iter_mut().zip(v.data) {
+            *row = order.map(|j| v_row[j]);
+        }
+        Ok(SymmetricEigen {
+            eigenvalues: order.map(|i| unsorted[i]),
+            eigenvectors,
+        })
+    }
+}
+
+/// The eigenvalues of the symmetric matrix `a` and an orthogonal matrix `V` of corresponding eigenvectors in its columns,
+/// found by cyclic Jacobi rotations reducing the off-diagonal entries of `VᵀAV` to zero. Only the lower triangle of `a` is read.
+/// If the off-diagonal entries are not negligible after [`MAX_SWEEPS`] sweeps, get [`None`] instead.
+fn symmetric_jacobi<const N: usize, T: MatrixEntry + Float>(
+    a: &SquareMatrix<N, T>,
+) -> Option<([T; N], SquareMatrix<N, T>)> {
+    let two = T::one() + T::one();
+    let mut a = *a;
+    for i in 0..N {
+        for j in i + 1..N {
+            a.data[i][j] = a.data[j][i];
+        }
+    }
+    let scale = a.frobenius_norm();
+    let mut v = SquareMatrix::<N, T>::new([[T::zero(); N]; N]);
+    for (i, row) in v.data.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..N)
+            .flat_map(|i| (0..i).map(move |j| (i, j)))
+            .fold(T::zero(), |sum, (i, j)| {
+                sum + two * a.data[i][j] * a.data[i][j]
+            });
+        if off_diagonal.sqrt() <= T::epsilon() * scale {
+            let mut eigenvalues = [T::zero(); N];
+            for (i, eigenvalue) in eigenvalues.iter_mut().enumerate() {
+                *eigenvalue = a.data[i][i];
+            }
+            return Some((eigenvalues, v));
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                let a_pq = a.data[p][q];
+                if a_pq.is_zero() {
+                    continue;
+                }
+                let theta = (a.data[q][q] - a.data[p][p]) / (two * a_pq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = (t * t + T::one()).sqrt().recip();
+                let s = t * c;
+                for row in a.data.iter_mut().chain(v.data.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (row_p, row_q) = (a.data[p], a.data[q]);
+                for (k, (x, y)) in row_p.into_iter().zip(row_q).enumerate() {
+                    a.data[p][k] = c * x - s * y;
+                    a.data[q][k] = s * x + c * y;
+                }
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,164 @@
+//! Deterministic matrices with well known properties, for testing solvers and decompositions reproducibly.
+
+use num_traits::{Float, FromPrimitive, One, Zero};
+use std::ops::Add;
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+/// Check at compile time that magic squares of size `n` can be built, which they can for odd or doubly even sizes.
+const fn assert_magic_square_size(n: usize) {
+    assert!(
+        n % 2 == 1 || n % 4 == 0,
+        "magic squares are only built for odd or doubly even sizes"
+    );
+}
+
+/// Convert `value` to `T`, which fixtures are only used with when it fits.
+fn entry<T: FromPrimitive>(value: usize) -> T {
+    T::from_usize(value).expect("fixture entry does not fit in the entry type")
+}
+
+/// The Wilson matrix, a symmetric positive definite integer matrix with determinant one
+/// and a condition number of about 3000.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{Factorization, Lu};
+/// # use malg::test_matrices::wilson;
+/// let a = wilson::<f64>();
+/// assert!((Lu::new(&a).det() - 1.0).abs() < 1e-12);
+/// assert_eq!(a, a.transpose());
+/// ```
+pub fn wilson<T: MatrixEntry + From<u8>>() -> SquareMatrix<4, T> {
+    SquareMatrix::new(
+        [[10, 7, 8, 7], [7, 5, 6, 5], [8, 6, 10, 9], [7, 5, 9, 10]].map(|row| row.map(T::from)),
+    )
+}
+
+/// The `N`-by-`N` symmetric Pascal matrix, with entries the binomial coefficients `(i+j choose i)`.
+/// It is positive definite with determinant one, and its Cholesky factor is the lower triangular Pascal matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::SquareMatrix;
+/// # use malg::test_matrices::pascal;
+/// let a = pascal::<4, u32>();
+/// assert_eq!(a, SquareMatrix::new([[1, 1, 1, 1], [1, 2, 3, 4], [1, 3, 6, 10], [1, 4, 10, 20]]));
+/// assert_eq!(a.determinant_exact(), Ok(1));
+/// ```
+pub fn pascal<const N: usize, T: MatrixEntry + Add<Output = T> + One>() -> SquareMatrix<N, T> {
+    let mut data = [[T::one(); N]; N];
+    for i in 1..N {
+        for j in 1..N {
+            data[i][j] = data[i - 1][j] + data[i][j - 1];
+        }
+    }
+    SquareMatrix::new(data)
+}
+
+/// The `N`-by-`N` Hilbert matrix, with entries `1/(i+j+1)`, a classic example of a badly conditioned matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::test_matrices::hilbert;
+/// let a = hilbert::<3, f64>();
+/// assert_eq!(a.get_entry(1, 2), Some(&0.25));
+/// ```
+pub fn hilbert<const N: usize, T: MatrixEntry + Float>() -> SquareMatrix<N, T> {
+    let mut data = [[T::zero(); N]; N];
+    for (i, row) in data.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry = T::one() / T::from(i + j + 1).expect("index does not fit in the entry type");
+        }
+    }
+    SquareMatrix::new(data)
+}
+
+/// An `N`-by-`N` magic square holding each of `1` to `N²` once, with every row, column and diagonal
+/// summing to `N(N²+1)/2`.
+///
+/// `N` must be odd or a multiple of four. Odd squares are built with the Siamese method, and
+/// doubly even squares by complementing the entries on the diagonals of each 4×4 block.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::SquareMatrix;
+/// # use malg::test_matrices::magic_square;
+/// assert_eq!(magic_square::<3, u8>(), SquareMatrix::new([[8, 1, 6], [3, 5, 7], [4, 9, 2]]));
+///
+/// let a = magic_square::<4, u32>();
+/// let sums = a.as_slice().map(|row| row.iter().sum::<u32>());
+/// assert_eq!(sums, [34; 4]);
+/// ```
+///
+/// Singly even sizes are not supported.
+///
+/// ```compile_fail
+/// # use malg::test_matrices::magic_square;
+/// let a = magic_square::<6, u32>();
+/// ```
+pub fn magic_square<const N: usize, T: MatrixEntry + FromPrimitive>() -> SquareMatrix<N, T> {
+    const { assert_magic_square_size(N) };
+    let mut data = [[T::default(); N]; N];
+    if N % 2 == 1 {
+        let (mut i, mut j) = (0, N / 2);
+        for value in 1..=N * N {
+            data[i][j] = entry(value);
+            if value % N == 0 {
+                i = (i + 1) % N;
+            } else {
+                i = (i + N - 1) % N;
+                j = (j + 1) % N;
+            }
+        }
+    } else {
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let on_diagonal = i % 4 == j % 4 || i % 4 + j % 4 == 3;
+                let k = i * N + j + 1;
+                *value = entry(if on_diagonal { N * N + 1 - k } else { k });
+            }
+        }
+    }
+    SquareMatrix::new(data)
+}
+
+/// An `M`-by-`N` integer matrix of rank exactly `rank`, the sum of the outer products `uₖvₖᵀ` for `k < rank`
+/// where `uₖ = [1, 2ᵏ, 3ᵏ, ...]` and `vₖ` likewise.
+///
+/// # Panics
+///
+/// Panics if `rank` exceeds `M` or `N`, or if an entry does not fit in `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::Matrix;
+/// # use malg::test_matrices::rank_deficient;
+/// let a = rank_deficient::<3, 3, i64>(2);
+/// assert_eq!(a, Matrix::new([[2, 3, 4], [3, 5, 7], [4, 7, 10]]));
+/// assert_eq!(a.determinant_exact(), Ok(0));
+/// ```
+pub fn rank_deficient<const M: usize, const N: usize, T: MatrixEntry + FromPrimitive + Zero>(
+    rank: usize,
+) -> Matrix<M, N, T> {
+    assert!(
+        rank <= M && rank <= N,
+        "rank cannot exceed the dimensions of the matrix"
+    );
+    let mut data = [[T::zero(); N]; M];
+    for (i, row) in data.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            let base = (i + 1) * (j + 1);
+            let sum = (0..rank as u32)
+                .try_fold(0usize, |sum, k| sum.checked_add(base.checked_pow(k)?))
+                .expect("fixture entry does not fit in the entry type");
+            *value = entry(sum);
+        }
+    }
+    Matrix::new(data)
+}
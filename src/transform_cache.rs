@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    ops::{Add, Mul},
+};
+
+use num_traits::{Float, One, Zero};
+
+use crate::{dim, MatrixEntry, SquareMatrix};
+
+/// Handle to a matrix interned in a [`TransformCache`].
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Copy)]
+pub struct TransformKey(usize);
+
+/// Cache of interned `N`-by-`N` transforms with entries of type `T`, memoizing products of cached transforms.
+///
+/// Equal matrices are interned to the same [`TransformKey`], so repeatedly multiplying the same handful of
+/// transforms only computes each distinct product once. Interning compares against every cached matrix,
+/// so the cache is intended for small sets of frequently reused transforms.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TransformCache<const N: usize, T: MatrixEntry> {
+    matrices: Vec<SquareMatrix<N, T>>,
+    products: HashMap<(TransformKey, TransformKey), TransformKey>,
+    rotations: HashMap<(usize, u32, u32), TransformKey>,
+}
+
+impl<const N: usize, T: MatrixEntry> TransformCache<N, T> {
+    /// An empty [`TransformCache`].
+    pub fn new() -> Self {
+        TransformCache::<N, T> {
+            matrices: Vec::new(),
+            products: HashMap::new(),
+            rotations: HashMap::new(),
+        }
+    }
+    /// Intern `matrix`, returning the key of an equal cached matrix if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{SquareMatrix, TransformCache};
+    /// let mut cache = TransformCache::<2,i32>::new();
+    /// let a = cache.intern(SquareMatrix::new([[1, 2], [3, 4]]));
+    /// let b = cache.intern(SquareMatrix::new([[1, 2], [3, 4]]));
+    /// assert_eq!(a, b);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn intern(&mut self, matrix: SquareMatrix<N, T>) -> TransformKey {
+        match self.matrices.iter().position(|cached| *cached == matrix) {
+            Some(index) => TransformKey(index),
+            None => {
+                self.matrices.push(matrix);
+                TransformKey(self.matrices.len() - 1)
+            }
+        }
+    }
+    /// The cached matrix with key `key`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `key` was issued by a different cache.
+    pub fn get(&self, key: TransformKey) -> &SquareMatrix<N, T> {
+        &self.matrices[key.0]
+    }
+    /// The number of distinct cached matrices.
+    pub fn len(&self) -> usize {
+        self.matrices.len()
+    }
+    /// Whether the cache holds no matrices.
+    pub fn is_empty(&self) -> bool {
+        self.matrices.is_empty()
+    }
+}
+
+impl<const N: usize, T: MatrixEntry> Default for TransformCache<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Mul<Output = T> + Add<Output = T>> TransformCache<N, T> {
+    /// The key of the product of the cached matrices with keys `a` and `b`, computing and interning it only the first time it is requested.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `a` or `b` was issued by a different cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{SquareMatrix, TransformCache};
+    /// let mut cache = TransformCache::<2,i32>::new();
+    /// let a = cache.intern(SquareMatrix::new([[1, 1], [0, 1]]));
+    ///
+    /// let a_squared = cache.product(a, a);
+    ///
+    /// assert_eq!(*cache.get(a_squared), SquareMatrix::new([[1, 2], [0, 1]]));
+    /// assert_eq!(cache.product(a, a), a_squared);
+    /// ```
+    pub fn product(&mut self, a: TransformKey, b: TransformKey) -> TransformKey {
+        if let Some(key) = self.products.get(&(a, b)) {
+            return *key;
+        }
+        let key = self.intern(*self.get(a) * *self.get(b));
+        self.products.insert((a, b), key);
+        key
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Zero + One> TransformCache<N, T> {
+    /// The key of the `N`-by-`N` identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use num_traits::One;
+    /// # use malg::{SquareMatrix, TransformCache};
+    /// let mut cache = TransformCache::<3,f64>::new();
+    /// let identity = cache.identity();
+    /// assert_eq!(*cache.get(identity), SquareMatrix::one());
+    /// ```
+    pub fn identity(&mut self) -> TransformKey {
+        self.intern(SquareMatrix::<N, T>::one())
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Float> TransformCache<N, T> {
+    /// The key of the rotation by `steps` increments of `1/steps_per_turn` of a full turn about coordinate axis `axis`
+    /// (`0`, `1` or `2` for `x`, `y` or `z`), acting on the first three coordinates and leaving any others unchanged.
+    /// For `N = 4` this is a homogeneous rotation transform.
+    ///
+    /// Angles are quantized to whole steps, so equivalent rotations share one cached matrix.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `axis` is not `0`, `1` or `2`, or if `steps_per_turn` is zero.
+    /// `N` must be at least three, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{SquareMatrix, TransformCache};
+    /// let mut cache = TransformCache::<3,f64>::new();
+    ///
+    /// let quarter_turn = cache.axis_rotation(2, 1, 4);
+    /// let rotation = cache.get(quarter_turn);
+    /// let expected = SquareMatrix::<3,f64>::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    /// assert!((*rotation - expected).as_slice().iter().flatten().all(|e| e.abs() < 1e-12));
+    ///
+    /// assert_eq!(cache.axis_rotation(2, 5, 4), quarter_turn);
+    /// assert_eq!(cache.axis_rotation(2, -3, 4), quarter_turn);
+    /// ```
+    pub fn axis_rotation(&mut self, axis: usize, steps: i64, steps_per_turn: u32) -> TransformKey {
+        const { dim::assert_at_least(N, 3, "axis rotations act on three coordinates") };
+        assert!(axis < 3, "axis must be 0, 1 or 2");
+        assert!(steps_per_turn > 0, "a turn must have at least one step");
+        let step = steps.rem_euclid(i64::from(steps_per_turn)) as u32;
+        if let Some(key) = self.rotations.get(&(axis, step, steps_per_turn)) {
+            return *key;
+        }
+        let two_pi = T::from(std::f64::consts::TAU).unwrap();
+        let angle = two_pi * T::from(step).unwrap() / T::from(steps_per_turn).unwrap();
+        let (sin, cos) = angle.sin_cos();
+        let (i, j) = ((axis + 1) % 3, (axis + 2) % 3);
+        let mut rotation = SquareMatrix::<N, T>::one();
+        rotation.data[i][i] = cos;
+        rotation.data[i][j] = -sin;
+        rotation.data[j][i] = sin;
+        rotation.data[j][j] = cos;
+        let key = self.intern(rotation);
+        self.rotations.insert((axis, step, steps_per_turn), key);
+        key
+    }
+}
@@ -0,0 +1,89 @@
+use std::ops::{Add, Mul};
+
+use crate::{Matrix, MatrixEntry, SquareMatrix};
+
+impl<const M: usize, const N: usize, T: MatrixEntry + Add<Output = T> + Mul<Output = T>>
+    Matrix<M, N, T>
+{
+    /// Add `αB` to `self` in place, in one pass without the temporary matrix built by `a + b * alpha`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let mut a = Matrix::<2,2,f64>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Matrix::<2,2,f64>::new([[1.0, 0.0], [-2.0, 1.0]]);
+    ///
+    /// a.scaled_add(0.5, &b);
+    ///
+    /// assert_eq!(a, Matrix::new([[1.5, 2.0], [2.0, 4.5]]));
+    /// ```
+    pub fn scaled_add(&mut self, alpha: T, other: &Matrix<M, N, T>) {
+        for (entry, other_entry) in self
+            .data
+            .iter_mut()
+            .flatten()
+            .zip(other.data.iter().flatten())
+        {
+            *entry = *entry + alpha * *other_entry;
+        }
+    }
+
+    /// Add the scaled outer product `αuvᵀ` to `self` in place, without forming the outer product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let mut a = Matrix::<2,3,i32>::new([[1, 0, 0], [0, 1, 0]]);
+    /// let u = Matrix::<2,1,i32>::new([[1], [2]]);
+    /// let v = Matrix::<3,1,i32>::new([[1], [0], [-1]]);
+    ///
+    /// a.rank1_update(3, &u, &v);
+    ///
+    /// assert_eq!(a, Matrix::new([[4, 0, -3], [6, 1, -6]]));
+    /// ```
+    pub fn rank1_update(&mut self, alpha: T, u: &Matrix<M, 1, T>, v: &Matrix<N, 1, T>) {
+        for (row, [u_i]) in self.data.iter_mut().zip(u.data) {
+            let scaled = alpha * u_i;
+            for (entry, [v_j]) in row.iter_mut().zip(v.data) {
+                *entry = *entry + scaled * v_j;
+            }
+        }
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Add<Output = T> + Mul<Output = T>> SquareMatrix<N, T> {
+    /// Add the scaled product `αBBᵀ` to `self` in place, without forming `BBᵀ`.
+    ///
+    /// Since `BBᵀ` is symmetric, only the lower triangle is computed and mirrored into the upper triangle,
+    /// so a symmetric `self` stays exactly symmetric.
+    ///
+    /// # Examples
+    ///
+    /// Accumulate the scatter matrix of some points, one column per point.
+    ///
+    /// ```
+    /// # use malg::{Matrix, SquareMatrix};
+    /// let mut scatter = SquareMatrix::<2,f64>::new([[0.0; 2]; 2]);
+    /// let points = Matrix::<2,3,f64>::new([[1.0, -1.0, 0.0], [2.0, 0.0, 1.0]]);
+    ///
+    /// scatter.rank_k_update(0.5, &points);
+    ///
+    /// assert_eq!(scatter, SquareMatrix::new([[1.0, 1.0], [1.0, 2.5]]));
+    /// ```
+    pub fn rank_k_update<const K: usize>(&mut self, alpha: T, b: &Matrix<N, K, T>) {
+        for i in 0..N {
+            for j in 0..=i {
+                let b_b = b.data[i]
+                    .iter()
+                    .zip(b.data[j].iter())
+                    .fold(T::default(), |sum, (b_ik, b_jk)| sum + *b_ik * *b_jk);
+                self.data[i][j] = self.data[i][j] + alpha * b_b;
+                if i != j {
+                    self.data[j][i] = self.data[j][i] + alpha * b_b;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use num_traits::Zero;
+
+use crate::{Matrix, MatrixEntry};
+
+impl<const M: usize, const N: usize, T: MatrixEntry> Matrix<M, N, T> {
+    /// Create a matrix from the rows `data`, checking that every entry satisfies `predicate`.
+    /// If an entry does not, get [`None`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let probabilities = Matrix::<1,3,f64>::new_checked([[0.2, 0.5, 0.3]], |p| (0.0..=1.0).contains(p));
+    /// assert_eq!(probabilities, Some(Matrix::new([[0.2, 0.5, 0.3]])));
+    ///
+    /// let finite = Matrix::<1,2,f64>::new_checked([[1.0, f64::NAN]], |x| x.is_finite());
+    /// assert_eq!(finite, None);
+    /// ```
+    pub fn new_checked(data: [[T; N]; M], predicate: impl Fn(&T) -> bool) -> Option<Self> {
+        if data.iter().flatten().all(predicate) {
+            Some(Matrix::new(data))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const M: usize, const N: usize, T: MatrixEntry + PartialOrd + Zero> Matrix<M, N, T> {
+    /// Whether every entry of `self` is greater than or equal to zero.
+    /// Entries that cannot be compared with zero, such as NaN, are not non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// assert!(Matrix::<2,2,f64>::new([[0.0, 0.5], [0.25, 0.25]]).is_nonnegative());
+    /// assert!(!Matrix::<2,2,f64>::new([[0.0, 0.5], [f64::NAN, 0.25]]).is_nonnegative());
+    /// ```
+    pub fn is_nonnegative(&self) -> bool {
+        self.data.iter().flatten().all(|entry| *entry >= T::zero())
+    }
+
+    /// Whether every entry of `self` is strictly greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// assert!(Matrix::<1,3,i32>::new([[1, 2, 3]]).is_positive());
+    /// assert!(!Matrix::<1,3,i32>::new([[1, 0, 3]]).is_positive());
+    /// ```
+    pub fn is_positive(&self) -> bool {
+        self.data.iter().flatten().all(|entry| *entry > T::zero())
+    }
+
+    /// Whether every entry of `self` lies in the closed interval `[min, max]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let probabilities = Matrix::<2,2,f64>::new([[0.0, 1.0], [0.3, 0.7]]);
+    /// assert!(probabilities.all_in_range(0.0, 1.0));
+    /// assert!(!probabilities.all_in_range(0.0, 0.9));
+    /// ```
+    pub fn all_in_range(&self, min: T, max: T) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .all(|entry| min <= *entry && *entry <= max)
+    }
+
+    /// The mask of entries of `self` violating [`Matrix::is_nonnegative`], `true` where an entry is not non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,i32>::new([[1, -2], [0, -1]]);
+    /// assert_eq!(a.negative_mask(), Matrix::new([[false, true], [false, true]]));
+    /// ```
+    pub fn negative_mask(&self) -> Matrix<M, N, bool> {
+        self.violations(|entry| *entry >= T::zero())
+    }
+
+    /// The mask of entries of `self` violating [`Matrix::is_positive`], `true` where an entry is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<2,2,i32>::new([[1, -2], [0, 3]]);
+    /// assert_eq!(a.nonpositive_mask(), Matrix::new([[false, true], [true, false]]));
+    /// ```
+    pub fn nonpositive_mask(&self) -> Matrix<M, N, bool> {
+        self.violations(|entry| *entry > T::zero())
+    }
+
+    /// The mask of entries of `self` violating [`Matrix::all_in_range`], `true` where an entry lies outside `[min, max]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let intensities = Matrix::<1,4,f64>::new([[0.0, 1.2, 0.5, -0.1]]);
+    /// assert_eq!(intensities.out_of_range_mask(0.0, 1.0), Matrix::new([[false, true, false, true]]));
+    /// ```
+    pub fn out_of_range_mask(&self, min: T, max: T) -> Matrix<M, N, bool> {
+        self.violations(|entry| min <= *entry && *entry <= max)
+    }
+
+    /// The mask of entries of `self` that do not satisfy `valid`.
+    fn violations(&self, valid: impl Fn(&T) -> bool) -> Matrix<M, N, bool> {
+        Matrix::new(self.data.map(|row| row.map(|entry| !valid(&entry))))
+    }
+}
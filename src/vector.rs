@@ -0,0 +1,179 @@
+use num_traits::{Float, Zero};
+use std::ops::{Add, Mul};
+
+use crate::{dim, Matrix, MatrixEntry};
+
+/// Column vector with `N` entries of type `T`.
+pub type ColumnVector<const N: usize, T> = Matrix<N, 1, T>;
+
+/// Row vector with `N` entries of type `T`.
+pub type RowVector<const N: usize, T> = Matrix<1, N, T>;
+
+impl<const N: usize, T: MatrixEntry + Zero + Add<Output = T> + Mul<Output = T>> ColumnVector<N, T> {
+    /// The dot product of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::ColumnVector;
+    /// let u = ColumnVector::<3,i32>::new([[1], [2], [3]]);
+    /// let v = ColumnVector::<3,i32>::new([[4], [-5], [6]]);
+    /// assert_eq!(u.dot(&v), 12);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .fold(T::zero(), |sum, (a, b)| sum + a[0] * b[0])
+    }
+}
+
+impl<const N: usize, T: MatrixEntry> ColumnVector<N, T> {
+    /// The direct sum of `self` and `other`, the vector of the entries of `self` followed by those of `other`,
+    /// such as the state of a system composed of two subsystems.
+    ///
+    /// The length `P` of the result is checked to be `N + M`, failing to compile otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::ColumnVector;
+    /// let position = ColumnVector::<2,f64>::new([[1.0], [2.0]]);
+    /// let velocity = ColumnVector::<2,f64>::new([[-0.5], [0.0]]);
+    /// let state: ColumnVector<4, f64> = position.concat(&velocity);
+    /// assert_eq!(state, ColumnVector::new([[1.0], [2.0], [-0.5], [0.0]]));
+    /// ```
+    pub fn concat<const M: usize, const P: usize>(
+        &self,
+        other: &ColumnVector<M, T>,
+    ) -> ColumnVector<P, T> {
+        const {
+            dim::assert_sum(
+                P,
+                N,
+                M,
+                "the concatenation must have the length of both vectors",
+            )
+        };
+        let mut entries = [[T::default()]; P];
+        for (entry, other_entry) in entries
+            .iter_mut()
+            .zip(self.data.iter().chain(other.data.iter()))
+        {
+            *entry = *other_entry;
+        }
+        Matrix::new(entries)
+    }
+}
+
+impl<const N: usize, T: MatrixEntry + Mul<Output = T>> ColumnVector<N, T> {
+    /// The tensor (Kronecker) product of `self` and `other`, whose entry `iM + j` is the product of entry `i` of `self`
+    /// and entry `j` of `other`, such as the joint state of two independent subsystems.
+    ///
+    /// The length `P` of the result is checked to be `NM`, failing to compile otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::ColumnVector;
+    /// let coin = ColumnVector::<2,f64>::new([[0.5], [0.5]]);
+    /// let die = ColumnVector::<3,f64>::new([[0.25], [0.25], [0.5]]);
+    /// let joint: ColumnVector<6, f64> = coin.tensor(&die);
+    /// assert_eq!(joint, ColumnVector::new([[0.125], [0.125], [0.25], [0.125], [0.125], [0.25]]));
+    /// ```
+    pub fn tensor<const M: usize, const P: usize>(
+        &self,
+        other: &ColumnVector<M, T>,
+    ) -> ColumnVector<P, T> {
+        const {
+            dim::assert_product(
+                P,
+                N,
+                M,
+                "the tensor product must have the product of the lengths",
+            )
+        };
+        let mut entries = [[T::default()]; P];
+        for (block, [a]) in entries.chunks_exact_mut(M.max(1)).zip(self.data.iter()) {
+            for ([entry], [b]) in block.iter_mut().zip(other.data.iter()) {
+                *entry = *a * *b;
+            }
+        }
+        Matrix::new(entries)
+    }
+}
+
+/// The angle in radians between the vectors `u` and `v`, or `None` if either is zero.
+///
+/// The angle is computed as `2 atan2(‖û - v̂‖, ‖û + v̂‖)` from the unit vectors `û` and `v̂`,
+/// which stays accurate for nearly parallel and nearly opposite vectors.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{angle_between, ColumnVector};
+/// let u = ColumnVector::<2,f64>::new([[1.0], [0.0]]);
+/// let v = ColumnVector::<2,f64>::new([[1.0], [1.0]]);
+/// assert!((angle_between(&u, &v).unwrap() - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+/// assert_eq!(angle_between(&u, &ColumnVector::new([[0.0], [0.0]])), None);
+/// ```
+pub fn angle_between<const N: usize, T: MatrixEntry + Float>(
+    u: &ColumnVector<N, T>,
+    v: &ColumnVector<N, T>,
+) -> Option<T> {
+    let u_norm = u.dot(u).sqrt();
+    let v_norm = v.dot(v).sqrt();
+    if u_norm.is_zero() || v_norm.is_zero() {
+        return None;
+    }
+    let u_hat = *u * u_norm.recip();
+    let v_hat = *v * v_norm.recip();
+    let difference = u_hat - v_hat;
+    let sum = u_hat + v_hat;
+    let two = T::one() + T::one();
+    Some(
+        two * difference
+            .dot(&difference)
+            .sqrt()
+            .atan2(sum.dot(&sum).sqrt()),
+    )
+}
+
+/// The projection `(u·v / v·v) v` of the vector `u` onto the vector `v`, or `None` if `v` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{project_onto, ColumnVector};
+/// let u = ColumnVector::<3,f64>::new([[2.0], [3.0], [4.0]]);
+/// let v = ColumnVector::<3,f64>::new([[0.0], [2.0], [0.0]]);
+/// assert_eq!(project_onto(&u, &v), Some(ColumnVector::new([[0.0], [3.0], [0.0]])));
+/// ```
+pub fn project_onto<const N: usize, T: MatrixEntry + Float>(
+    u: &ColumnVector<N, T>,
+    v: &ColumnVector<N, T>,
+) -> Option<ColumnVector<N, T>> {
+    let v_v = v.dot(v);
+    if v_v.is_zero() {
+        return None;
+    }
+    Some(*v * (u.dot(v) / v_v))
+}
+
+/// The rejection `u - proj_v(u)` of the vector `u` from the vector `v`, the component of `u` orthogonal to `v`,
+/// or `None` if `v` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use malg::{reject_from, ColumnVector};
+/// let u = ColumnVector::<3,f64>::new([[2.0], [3.0], [4.0]]);
+/// let v = ColumnVector::<3,f64>::new([[0.0], [2.0], [0.0]]);
+/// assert_eq!(reject_from(&u, &v), Some(ColumnVector::new([[2.0], [0.0], [4.0]])));
+/// ```
+pub fn reject_from<const N: usize, T: MatrixEntry + Float>(
+    u: &ColumnVector<N, T>,
+    v: &ColumnVector<N, T>,
+) -> Option<ColumnVector<N, T>> {
+    project_onto(u, v).map(|projection| *u - projection)
+}
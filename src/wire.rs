@@ -0,0 +1,196 @@
+use std::{error::Error, fmt};
+
+use crate::{Matrix, MatrixEntry};
+
+/// Number of bytes in the header written by [`Matrix::to_bytes`]: an endianness flag, a dtype tag, and the number of rows and columns as `u32`s.
+pub const WIRE_HEADER_LEN: usize = 10;
+
+const LITTLE_ENDIAN: u8 = 0;
+const BIG_ENDIAN: u8 = 1;
+
+/// Entries that can be written to and read from the binary wire format of [`Matrix::to_bytes`].
+pub trait WireEntry: MatrixEntry {
+    /// Tag identifying the type of the entries in the header.
+    const DTYPE: u8;
+    /// Number of bytes in an encoded entry.
+    const SIZE: usize;
+    /// Append the entry to `out`, most significant byte first if `big_endian`.
+    fn write(&self, big_endian: bool, out: &mut Vec<u8>);
+    /// Read an entry from the [`WireEntry::SIZE`] bytes `bytes`.
+    fn read(bytes: &[u8], big_endian: bool) -> Self;
+}
+
+macro_rules! impl_wire_entry {
+    ($($t:ty => $tag:expr),*) => {
+        $(impl WireEntry for $t {
+            const DTYPE: u8 = $tag;
+            const SIZE: usize = std::mem::size_of::<$t>();
+            fn write(&self, big_endian: bool, out: &mut Vec<u8>) {
+                if big_endian {
+                    out.extend_from_slice(&self.to_be_bytes());
+                } else {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+            fn read(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().expect("entry has the wrong number of bytes");
+                if big_endian {
+                    <$t>::from_be_bytes(bytes)
+                } else {
+                    <$t>::from_le_bytes(bytes)
+                }
+            }
+        })*
+    };
+}
+impl_wire_entry!(
+    u8 => 0, u16 => 1, u32 => 2, u64 => 3, u128 => 4,
+    i8 => 5, i16 => 6, i32 => 7, i64 => 8, i128 => 9,
+    f32 => 10, f64 => 11
+);
+
+/// Reasons bytes cannot be decoded by [`Matrix::from_bytes`].
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum DecodeError {
+    /// The bytes are shorter than the header and entries they describe.
+    Truncated,
+    /// There are bytes left over after the last entry.
+    TrailingBytes,
+    /// The endianness flag is neither little nor big endian.
+    InvalidEndianness(u8),
+    /// The entries are of a different type than the one requested.
+    DtypeMismatch { expected: u8, found: u8 },
+    /// The matrix has different dimensions than the one requested.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated => write!(f, "encoded matrix is truncated"),
+            DecodeError::TrailingBytes => write!(f, "trailing bytes after encoded matrix"),
+            DecodeError::InvalidEndianness(flag) => write!(f, "invalid endianness flag {flag}"),
+            DecodeError::DtypeMismatch { expected, found } => {
+                write!(f, "expected dtype {expected}, found dtype {found}")
+            }
+            DecodeError::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found a {}x{} matrix",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+impl<const M: usize, const N: usize, T: WireEntry> Matrix<M, N, T> {
+    /// Encode the matrix as a [`WIRE_HEADER_LEN`]-byte header followed by its entries in row-major order, all in the native byte order.
+    ///
+    /// The header holds the byte order (`0` for little and `1` for big endian), the [`WireEntry::DTYPE`] of the entries, and the number of rows and columns as `u32`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{Matrix, WIRE_HEADER_LEN};
+    /// let a = Matrix::<2,3,i16>::new([[1, -2, 3], [4, 5, -6]]);
+    /// let bytes = a.to_bytes();
+    /// assert_eq!(bytes.len(), WIRE_HEADER_LEN + 6 * 2);
+    /// assert_eq!(Matrix::<2,3,i16>::from_bytes(&bytes), Ok(a));
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_with_endianness(cfg!(target_endian = "big"))
+    }
+
+    /// Encode the matrix like [`Matrix::to_bytes`], but most significant byte first if `big_endian`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::Matrix;
+    /// let a = Matrix::<1,2,u16>::new([[1, 0x0203]]);
+    /// assert_eq!(a.to_bytes_with_endianness(true), [1, 1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 1, 2, 3]);
+    /// assert_eq!(a.to_bytes_with_endianness(false), [0, 1, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 3, 2]);
+    /// ```
+    pub fn to_bytes_with_endianness(&self, big_endian: bool) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(WIRE_HEADER_LEN + M * N * T::SIZE);
+        bytes.push(if big_endian {
+            BIG_ENDIAN
+        } else {
+            LITTLE_ENDIAN
+        });
+        bytes.push(T::DTYPE);
+        for dim in [M, N] {
+            let dim = u32::try_from(dim).expect("dimension does not fit in a u32");
+            if big_endian {
+                bytes.extend_from_slice(&dim.to_be_bytes());
+            } else {
+                bytes.extend_from_slice(&dim.to_le_bytes());
+            }
+        }
+        for entry in self.data.iter().flatten() {
+            entry.write(big_endian, &mut bytes);
+        }
+        bytes
+    }
+
+    /// Decode a matrix written by [`Matrix::to_bytes`] in either byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use malg::{DecodeError, Matrix};
+    /// let a = Matrix::<2,2,f32>::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let bytes = a.to_bytes_with_endianness(true);
+    /// assert_eq!(Matrix::<2,2,f32>::from_bytes(&bytes), Ok(a));
+    /// assert!(matches!(Matrix::<2,2,f64>::from_bytes(&bytes), Err(DecodeError::DtypeMismatch { .. })));
+    /// assert!(matches!(Matrix::<1,4,f32>::from_bytes(&bytes), Err(DecodeError::DimensionMismatch { .. })));
+    /// assert_eq!(Matrix::<2,2,f32>::from_bytes(&bytes[..bytes.len() - 1]), Err(DecodeError::Truncated));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < WIRE_HEADER_LEN {
+            return Err(DecodeError::Truncated);
+        }
+        let big_endian = match bytes[0] {
+            LITTLE_ENDIAN => false,
+            BIG_ENDIAN => true,
+            flag => return Err(DecodeError::InvalidEndianness(flag)),
+        };
+        if bytes[1] != T::DTYPE {
+            return Err(DecodeError::DtypeMismatch {
+                expected: T::DTYPE,
+                found: bytes[1],
+            });
+        }
+        let dim = |offset: usize| {
+            let dim = bytes[offset..offset + 4].try_into().unwrap();
+            let dim = if big_endian {
+                u32::from_be_bytes(dim)
+            } else {
+                u32::from_le_bytes(dim)
+            };
+            dim as usize
+        };
+        let found = (dim(2), dim(6));
+        if found != (M, N) {
+            return Err(DecodeError::DimensionMismatch {
+                expected: (M, N),
+                found,
+            });
+        }
+        let entries = &bytes[WIRE_HEADER_LEN..];
+        match entries.len().cmp(&(M * N * T::SIZE)) {
+            std::cmp::Ordering::Less => return Err(DecodeError::Truncated),
+            std::cmp::Ordering::Greater => return Err(DecodeError::TrailingBytes),
+            std::cmp::Ordering::Equal => {}
+        }
+        let mut data = [[T::default(); N]; M];
+        for (entry, bytes) in data.iter_mut().flatten().zip(entries.chunks_exact(T::SIZE)) {
+            *entry = T::read(bytes, big_endian);
+        }
+        Ok(Matrix { data })
+    }
+}